@@ -1,8 +1,10 @@
 #![allow(dead_code)]
 
 use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::PyKeyError;
 use pyo3::exceptions::PyTypeError;
 use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::PyZeroDivisionError;
 use pyo3::prelude::*;
 use pyo3::pyclass;
 use pyo3::types::PyIterator;
@@ -11,6 +13,8 @@ use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
 use pyo3::types::PyAny;
+use pyo3::types::PyBool;
+use pyo3::types::PyDict;
 use pyo3::types::PyRange;
 use pyo3::types::PyType;
 use pyo3::PyResult;
@@ -76,6 +80,117 @@ pub fn extract_integer_quad(pair: &Bound<PyAny>) -> PyResult<(i32, i32, i32, i32
     }
 }
 
+pub fn extract_offset(offset: &Bound<PyAny>) -> PyResult<(i32, i32)> {
+    if let Ok(offset) = offset.extract::<GeometryOffset>() {
+        return Ok((offset.x, offset.y));
+    }
+    extract_integer_pair(offset)
+}
+
+/// Extract a pair of integers from a tuple, or any object with integer `x` and `y` attributes.
+/// Our other geometry types are rejected, so a Region isn't mistaken for its origin.
+fn extract_offset_like(offset: &Bound<PyAny>) -> Option<(i32, i32)> {
+    if let Ok(pair) = offset.extract::<(i32, i32)>() {
+        return Some(pair);
+    }
+    if offset.is_instance_of::<Region>()
+        || offset.is_instance_of::<Size>()
+        || offset.is_instance_of::<Spacing>()
+    {
+        return None;
+    }
+    let x = offset.getattr("x").ok()?.extract::<i32>().ok()?;
+    let y = offset.getattr("y").ok()?.extract::<i32>().ok()?;
+    Some((x, y))
+}
+
+pub fn extract_size(size: &Bound<PyAny>) -> PyResult<(i32, i32)> {
+    if let Ok(size) = size.extract::<Size>() {
+        return Ok((size.width, size.height));
+    }
+    extract_integer_pair(size)
+}
+
+pub fn count_value(values: &[i32], value: &Bound<PyAny>) -> usize {
+    match value.extract::<i32>() {
+        Ok(value) => values.iter().filter(|&&v| v == value).count(),
+        _ => 0,
+    }
+}
+
+pub fn index_value(values: &[i32], value: &Bound<PyAny>) -> PyResult<usize> {
+    value
+        .extract::<i32>()
+        .ok()
+        .and_then(|value| values.iter().position(|&v| v == value))
+        .ok_or_else(|| PyValueError::new_err("value not found"))
+}
+
+pub fn round_to_int(value: f64, mode: &str) -> PyResult<i32> {
+    match mode {
+        "floor" => Ok(value.floor() as i32),
+        "round" => Ok(value.round_ties_even() as i32),
+        "ceil" => Ok(value.ceil() as i32),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid rounding mode {:?}, expected 'floor', 'round', or 'ceil'",
+            mode
+        ))),
+    }
+}
+
+fn divide_span(start: i32, length: i32, count: i32, gutter: i32) -> Vec<(i32, i32)> {
+    let length = length.max(0);
+    // Narrow gutters that won't fit, so cells and gutters always tile the span exactly
+    let gutter = if count > 1 {
+        gutter.min(length / (count - 1))
+    } else {
+        gutter
+    };
+    let available = length - gutter * (count - 1);
+    let (size, remainder) = (available / count, available % count);
+    let mut position = start;
+    (0..count)
+        .map(|index| {
+            let span = (position, size + i32::from(index < remainder));
+            position += span.1 + gutter;
+            span
+        })
+        .collect()
+}
+
+fn weighted_spans(start: i32, length: i32, weights: &[i32]) -> PyResult<Vec<(i32, i32)>> {
+    if weights.iter().any(|&weight| weight < 0) {
+        return Err(PyValueError::new_err("Weights must not be negative"));
+    }
+    let total: i64 = weights.iter().map(|&weight| weight as i64).sum();
+    if total <= 0 {
+        return Err(PyValueError::new_err("Weights must sum to more than zero"));
+    }
+    let length = length.max(0) as i64;
+    let sizes: Vec<i64> = weights
+        .iter()
+        .map(|&weight| length * weight as i64 / total)
+        .collect();
+    // Hand out the cells lost to rounding down, left to right among weighted spans
+    let mut remainder = length - sizes.iter().sum::<i64>();
+    let mut position = start;
+    Ok(weights
+        .iter()
+        .zip(sizes)
+        .map(|(&weight, size)| {
+            let extra = i64::from(weight > 0 && remainder > 0);
+            remainder -= extra;
+            let span = (position, (size + extra) as i32);
+            position += span.1;
+            span
+        })
+        .collect())
+}
+
+/// Restrict a value to the range between minimum and maximum (inclusive).
+///
+/// If minimum is greater than maximum, the bounds are swapped (matching Textual's `clamp`),
+/// so `clamp(5, 10, 0)` is 5 and `clamp(-1, 10, 0)` is 0.
 pub fn clamp<T: Ord + Copy>(value: T, minimum: T, maximum: T) -> T {
     if minimum > maximum {
         if value < maximum {
@@ -96,8 +211,8 @@ pub fn clamp<T: Ord + Copy>(value: T, minimum: T, maximum: T) -> T {
     }
 }
 
-#[pyclass(name = "Offset")]
-#[derive(Debug, Clone)]
+#[pyclass(name = "Offset", frozen)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GeometryOffset {
     #[pyo3(get)]
     pub x: i32,
@@ -108,9 +223,27 @@ pub struct GeometryOffset {
 #[pymethods]
 impl GeometryOffset {
     #[new]
-    #[pyo3(signature=(x=0, y=0))]
-    fn new(x: i32, y: i32) -> Self {
-        GeometryOffset { x, y }
+    #[pyo3(signature=(x=None, y=None))]
+    fn new(x: Option<&Bound<PyAny>>, y: Option<i32>) -> PyResult<Self> {
+        let Some(x) = x else {
+            return Ok(GeometryOffset {
+                x: 0,
+                y: y.unwrap_or(0),
+            });
+        };
+        if let Ok(x) = x.extract::<i32>() {
+            Ok(GeometryOffset {
+                x,
+                y: y.unwrap_or(0),
+            })
+        } else if y.is_some() {
+            Err(PyTypeError::new_err("Expected int for x"))
+        } else if let Ok(offset) = x.extract::<GeometryOffset>() {
+            Ok(offset)
+        } else {
+            let (x, y) = extract_integer_pair(x)?;
+            Ok(GeometryOffset { x, y })
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -135,6 +268,14 @@ impl GeometryOffset {
         (self.y, self.x)
     }
 
+    #[getter]
+    pub fn transposed(&self) -> GeometryOffset {
+        GeometryOffset {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
     fn __bool__(&self) -> bool {
         self.x != 0 || self.y != 0
     }
@@ -168,13 +309,21 @@ impl GeometryOffset {
         2
     }
 
+    fn count(&self, value: &Bound<PyAny>) -> usize {
+        count_value(&[self.x, self.y], value)
+    }
+
+    fn index(&self, value: &Bound<PyAny>) -> PyResult<usize> {
+        index_value(&[self.x, self.y], value)
+    }
+
     fn __add__(&self, rhs: &Bound<PyAny>) -> PyResult<GeometryOffset> {
         if let Ok(offset) = rhs.extract::<GeometryOffset>() {
             Ok(GeometryOffset {
                 x: self.x + offset.x,
                 y: self.y + offset.y,
             })
-        } else if let Ok((x, y)) = rhs.extract::<(i32, i32)>() {
+        } else if let Some((x, y)) = extract_offset_like(rhs) {
             Ok(GeometryOffset {
                 x: self.x + x,
                 y: self.y + y,
@@ -192,7 +341,7 @@ impl GeometryOffset {
                 x: self.x - offset.x,
                 y: self.y - offset.y,
             })
-        } else if let Ok((x, y)) = rhs.extract::<(i32, i32)>() {
+        } else if let Some((x, y)) = extract_offset_like(rhs) {
             Ok(GeometryOffset {
                 x: self.x - x,
                 y: self.y - y,
@@ -204,6 +353,14 @@ impl GeometryOffset {
         }
     }
 
+    fn __radd__(&self, lhs: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        self.__add__(lhs)
+    }
+
+    fn __rsub__(&self, lhs: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        Ok(self.__sub__(lhs)?.__neg__())
+    }
+
     fn __mul__(&self, rhs: &Bound<PyAny>) -> PyResult<GeometryOffset> {
         if let Ok(factor) = rhs.extract::<i32>() {
             Ok(GeometryOffset {
@@ -237,6 +394,30 @@ impl GeometryOffset {
         }
     }
 
+    fn __mod__(&self, size: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        let (width, height) = extract_size(size)?;
+        if width == 0 || height == 0 {
+            return Err(PyZeroDivisionError::new_err("Offset modulo by zero size"));
+        }
+        Ok(GeometryOffset {
+            x: (self.x as i64).rem_euclid(width as i64) as i32,
+            y: (self.y as i64).rem_euclid(height as i64) as i32,
+        })
+    }
+
+    #[pyo3(signature=(factor, *, round="floor"))]
+    fn scale(&self, factor: &Bound<PyAny>, round: &str) -> PyResult<GeometryOffset> {
+        let (x_factor, y_factor) = if let Ok(factor) = factor.extract::<f64>() {
+            (factor, factor)
+        } else {
+            factor.extract::<(f64, f64)>()?
+        };
+        Ok(GeometryOffset {
+            x: round_to_int(self.x as f64 * x_factor, round)?,
+            y: round_to_int(self.y as f64 * y_factor, round)?,
+        })
+    }
+
     fn __neg__(&self) -> Self {
         GeometryOffset {
             x: -self.x,
@@ -253,22 +434,194 @@ impl GeometryOffset {
         }
     }
 
+    pub fn lerp(&self, destination: GeometryOffset, factor: f64) -> GeometryOffset {
+        self.blend(destination, factor.clamp(0.0, 1.0))
+    }
+
+    fn iter_line_to(&self, other: GeometryOffset) -> OffsetLine {
+        let dx = (other.x as i64 - self.x as i64).abs();
+        let dy = -(other.y as i64 - self.y as i64).abs();
+        OffsetLine {
+            x: self.x,
+            y: self.y,
+            end_x: other.x,
+            end_y: other.y,
+            dx,
+            dy,
+            step_x: if other.x >= self.x { 1 } else { -1 },
+            step_y: if other.y >= self.y { 1 } else { -1 },
+            error: dx + dy,
+            done: false,
+        }
+    }
+
     pub fn get_distance_to(&self, other: GeometryOffset) -> f64 {
         let dx = (other.x - self.x) as f64;
         let dy = (other.y - self.y) as f64;
         (dx * dx + dy * dy).sqrt()
     }
 
-    pub fn clamp(&self, width: i32, height: i32) -> Self {
+    pub fn mirror_x(&self, width: i32) -> GeometryOffset {
+        GeometryOffset {
+            x: width - 1 - self.x,
+            y: self.y,
+        }
+    }
+
+    pub fn mirror_y(&self, height: i32) -> GeometryOffset {
+        GeometryOffset {
+            x: self.x,
+            y: height - 1 - self.y,
+        }
+    }
+
+    #[getter]
+    pub fn magnitude(&self) -> f64 {
+        (self.x as f64).hypot(self.y as f64)
+    }
+
+    pub fn clamp_magnitude(&self, max_len: f64) -> PyResult<GeometryOffset> {
+        if max_len.is_nan() || max_len < 0.0 {
+            return Err(PyValueError::new_err("max_len must not be negative"));
+        }
+        let magnitude = self.magnitude();
+        if magnitude <= max_len {
+            return Ok(GeometryOffset {
+                x: self.x,
+                y: self.y,
+            });
+        }
+        // Round towards zero so the result never exceeds max_len
+        let factor = max_len / magnitude;
+        Ok(GeometryOffset {
+            x: (self.x as f64 * factor).trunc() as i32,
+            y: (self.y as f64 * factor).trunc() as i32,
+        })
+    }
+
+    pub fn sign(&self) -> GeometryOffset {
+        GeometryOffset {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    pub fn get_distance_squared_to(&self, other: &Bound<PyAny>) -> PyResult<i128> {
+        let (x, y) = extract_offset(other)?;
+        let dx = x as i128 - self.x as i128;
+        let dy = y as i128 - self.y as i128;
+        Ok(dx * dx + dy * dy)
+    }
+
+    pub fn to_size(&self) -> Size {
+        // Negative components are kept as-is, as with Size(x, y)
+        Size {
+            width: self.x,
+            height: self.y,
+        }
+    }
+
+    #[classmethod]
+    pub fn region_between(
+        _cls: &Bound<'_, PyType>,
+        a: &Bound<PyAny>,
+        b: &Bound<PyAny>,
+    ) -> PyResult<Region> {
+        let (ax, ay) = extract_offset(a)?;
+        let (bx, by) = extract_offset(b)?;
+        let (x, y) = (ax.min(bx), ay.min(by));
+        Ok(Region {
+            x,
+            y,
+            width: ax.max(bx) - x + 1,
+            height: ay.max(by) - y + 1,
+        })
+    }
+
+    #[pyo3(signature=(size=None))]
+    pub fn to_region(&self, size: Option<&Bound<PyAny>>) -> PyResult<Region> {
+        let (width, height) = match size {
+            Some(size) => extract_size(size)?,
+            None => (1, 1),
+        };
+        Ok(Region {
+            x: self.x,
+            y: self.y,
+            width: width.max(0),
+            height: height.max(0),
+        })
+    }
+
+    pub fn min(&self, other: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        let (x, y) = extract_offset(other)?;
+        Ok(GeometryOffset {
+            x: self.x.min(x),
+            y: self.y.min(y),
+        })
+    }
+
+    pub fn max(&self, other: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        let (x, y) = extract_offset(other)?;
+        Ok(GeometryOffset {
+            x: self.x.max(x),
+            y: self.y.max(y),
+        })
+    }
+
+    pub fn clamp_between(
+        &self,
+        minimum: &Bound<PyAny>,
+        maximum: &Bound<PyAny>,
+    ) -> PyResult<GeometryOffset> {
+        let (min_x, min_y) = extract_offset(minimum)?;
+        let (max_x, max_y) = extract_offset(maximum)?;
+        // If the bounds are inverted, the minimum wins
+        Ok(GeometryOffset {
+            x: self.x.min(max_x).max(min_x),
+            y: self.y.min(max_y).max(min_y),
+        })
+    }
+
+    pub fn within(&self, region: &Region) -> bool {
+        region.contains(self.x, self.y)
+    }
+
+    pub fn is_between(&self, a: &Bound<PyAny>, b: &Bound<PyAny>) -> PyResult<bool> {
+        let (ax, ay) = extract_offset(a)?;
+        let (bx, by) = extract_offset(b)?;
+        // Each term of the cross product can need 64 bits, so the difference needs i128
+        let (x, y) = (self.x as i128, self.y as i128);
+        let (ax, ay, bx, by) = (ax as i128, ay as i128, bx as i128, by as i128);
+        // Collinear when the cross product is zero, then check the segment's bounding box
+        let cross = (bx - ax) * (y - ay) - (by - ay) * (x - ax);
+        Ok(cross == 0 && x >= ax.min(bx) && x <= ax.max(bx) && y >= ay.min(by) && y <= ay.max(by))
+    }
+
+    pub fn reflect(&self, region: &Region) -> GeometryOffset {
+        // Reflect cells as mirror_x / mirror_y do, so a cell inside the region stays inside
+        GeometryOffset {
+            x: (2 * region.x as i64 + region.width as i64 - 1 - self.x as i64) as i32,
+            y: (2 * region.y as i64 + region.height as i64 - 1 - self.y as i64) as i32,
+        }
+    }
+
+    #[pyo3(signature=(width, height, *, inclusive=false))]
+    pub fn clamp(&self, width: i32, height: i32, inclusive: bool) -> Self {
+        // A zero or negative dimension clamps that axis to 0
+        let (max_x, max_y) = if inclusive {
+            (width, height)
+        } else {
+            (width - 1, height - 1)
+        };
         GeometryOffset {
-            x: clamp(self.x, 0, width - 1),
-            y: clamp(self.y, 0, height - 1),
+            x: clamp(self.x, 0, max_x.max(0)),
+            y: clamp(self.y, 0, max_y.max(0)),
         }
     }
 }
 
 #[pyclass(frozen)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Size {
     #[pyo3(get)]
     pub width: i32,
@@ -279,9 +632,48 @@ pub struct Size {
 #[pymethods]
 impl Size {
     #[new]
-    #[pyo3(signature=(width=0, height=0))]
-    fn new(width: i32, height: i32) -> Self {
-        Size { width, height }
+    #[pyo3(signature=(width=None, height=None))]
+    fn new(width: Option<&Bound<PyAny>>, height: Option<i32>) -> PyResult<Self> {
+        let Some(width) = width else {
+            return Ok(Size {
+                width: 0,
+                height: height.unwrap_or(0),
+            });
+        };
+        if let Ok(width) = width.extract::<i32>() {
+            Ok(Size {
+                width,
+                height: height.unwrap_or(0),
+            })
+        } else if height.is_some() {
+            Err(PyTypeError::new_err("Expected int for width"))
+        } else if let Ok(size) = width.extract::<Size>() {
+            Ok(size)
+        } else {
+            let (width, height) = extract_integer_pair(width)?;
+            Ok(Size { width, height })
+        }
+    }
+
+    #[classmethod]
+    #[pyo3(signature=(area, aspect=1.0))]
+    fn for_area(_cls: &Bound<'_, PyType>, area: i32, aspect: f64) -> PyResult<Size> {
+        if area < 0 {
+            return Err(PyValueError::new_err("area must not be negative"));
+        }
+        if !(aspect.is_finite() && aspect > 0.0) {
+            return Err(PyValueError::new_err("aspect must be a positive number"));
+        }
+        if area == 0 {
+            return Ok(Size {
+                width: 0,
+                height: 0,
+            });
+        }
+        // aspect is width / height; the height is rounded up so width * height >= area
+        let width = ((area as f64 * aspect).sqrt().ceil() as i32).clamp(1, area);
+        let height = ((area as i64 + width as i64 - 1) / width as i64) as i32;
+        Ok(Size { width, height })
     }
 
     fn __repr__(&self) -> String {
@@ -297,11 +689,17 @@ impl Size {
         }
     }
 
-    fn __eq__(&self, rhs: &Bound<PyAny>) -> PyResult<bool> {
-        match extract_integer_pair(rhs) {
-            Ok(size) => Ok(self.width == size.0 && self.height == size.1),
-            _ => Ok(false),
-        }
+    /// A Size never equals a Region, even one with the same dimensions; compare against
+    /// `region.size` instead. Regions at different positions would otherwise both equal the
+    /// same Size, and their hashes can't agree with it, breaking set and dict lookups.
+    fn __eq__(&self, py: Python, rhs: &Bound<PyAny>) -> Py<PyAny> {
+        let Ok(size) = extract_integer_pair(rhs) else {
+            return py.NotImplemented();
+        };
+        PyBool::new(py, self.width == size.0 && self.height == size.1)
+            .to_owned()
+            .into_any()
+            .unbind()
     }
 
     fn __hash__(&self) -> isize {
@@ -315,6 +713,14 @@ impl Size {
         2
     }
 
+    fn count(&self, value: &Bound<PyAny>) -> usize {
+        count_value(&[self.width, self.height], value)
+    }
+
+    fn index(&self, value: &Bound<PyAny>) -> PyResult<usize> {
+        index_value(&[self.width, self.height], value)
+    }
+
     fn __bool__(&self) -> bool {
         return self.width * self.height != 0;
     }
@@ -355,6 +761,18 @@ impl Size {
         }
     }
 
+    fn __radd__(&self, size: &Bound<PyAny>) -> PyResult<Self> {
+        self.__add__(size)
+    }
+
+    fn __rsub__(&self, size: &Bound<PyAny>) -> PyResult<Self> {
+        let Size { width, height } = self.__sub__(size)?;
+        Ok(Size {
+            width: -width,
+            height: -height,
+        })
+    }
+
     #[getter]
     fn region(&self) -> Region {
         Region {
@@ -365,6 +783,25 @@ impl Size {
         }
     }
 
+    fn region_at(&self, offset: &Bound<PyAny>) -> PyResult<Region> {
+        let (x, y) = extract_offset(offset)?;
+        Ok(Region {
+            x,
+            y,
+            width: self.width,
+            height: self.height,
+        })
+    }
+
+    fn region_at_cell(&self, col: i32, row: i32) -> Region {
+        Region {
+            x: col * self.width,
+            y: row * self.height,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
     #[getter]
     fn area(&self) -> i32 {
         self.width * self.height
@@ -376,6 +813,29 @@ impl Size {
         Ok(range.into())
     }
 
+    fn to_offset(&self) -> GeometryOffset {
+        GeometryOffset {
+            x: self.width,
+            y: self.height,
+        }
+    }
+
+    fn min(&self, other: &Bound<PyAny>) -> PyResult<Size> {
+        let (width, height) = extract_size(other)?;
+        Ok(Size {
+            width: self.width.min(width),
+            height: self.height.min(height),
+        })
+    }
+
+    fn max(&self, other: &Bound<PyAny>) -> PyResult<Size> {
+        let (width, height) = extract_size(other)?;
+        Ok(Size {
+            width: self.width.max(width),
+            height: self.height.max(height),
+        })
+    }
+
     fn with_width(&self, width: i32) -> Size {
         Size {
             width,
@@ -390,6 +850,21 @@ impl Size {
         }
     }
 
+    fn snap_up(&self, cell_width: i32, cell_height: i32) -> PyResult<Size> {
+        if cell_width <= 0 || cell_height <= 0 {
+            return Err(PyValueError::new_err("Cell dimensions must be positive"));
+        }
+        Ok(Size {
+            width: (self.width + cell_width - 1).div_euclid(cell_width) * cell_width,
+            height: (self.height + cell_height - 1).div_euclid(cell_height) * cell_height,
+        })
+    }
+
+    fn fits_in(&self, other: &Bound<PyAny>) -> PyResult<bool> {
+        let (width, height) = extract_size(other)?;
+        Ok(self.width <= width && self.height <= height)
+    }
+
     fn contains(&self, x: i32, y: i32) -> bool {
         x >= 0 && x < self.width && y >= 0 && y < self.height
     }
@@ -415,12 +890,12 @@ impl Size {
     }
 
     fn clamp_offset(&self, offset: &GeometryOffset) -> GeometryOffset {
-        offset.clamp(self.width, self.height)
+        offset.clamp(self.width, self.height, false)
     }
 }
 
 #[pyclass(frozen)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Region {
     #[pyo3(get)]
     pub x: i32,
@@ -435,14 +910,50 @@ pub struct Region {
 #[pymethods]
 impl Region {
     #[new]
-    #[pyo3(signature=(x=0, y=0, width=0, height=0))]
-    fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
-        Region {
-            x,
-            y,
-            width,
-            height,
-        }
+    #[pyo3(signature=(x=None, y=None, width=None, height=None))]
+    fn new(
+        x: Option<&Bound<PyAny>>,
+        y: Option<i32>,
+        width: Option<i32>,
+        height: Option<i32>,
+    ) -> PyResult<Self> {
+        let single = y.is_none() && width.is_none() && height.is_none();
+        let y = y.unwrap_or(0);
+        let width = width.unwrap_or(0);
+        let height = height.unwrap_or(0);
+        let region = match x {
+            None => Region {
+                x: 0,
+                y,
+                width,
+                height,
+            },
+            Some(x) => {
+                if let Ok(x) = x.extract::<i32>() {
+                    Region {
+                        x,
+                        y,
+                        width,
+                        height,
+                    }
+                } else if !single {
+                    return Err(PyTypeError::new_err("Expected int for x"));
+                } else if let Ok(region) = x.extract::<Region>() {
+                    region
+                } else {
+                    let (x, y, width, height) = extract_integer_quad(x)?;
+                    Region {
+                        x,
+                        y,
+                        width,
+                        height,
+                    }
+                }
+            }
+        };
+        // Negative dimensions are normalized (corners swapped) rather than rejected, so
+        // right and bottom are never left of x and y
+        Ok(region._normalize())
     }
 
     fn __eq__(&self, rhs: &Region) -> bool {
@@ -489,6 +1000,24 @@ impl Region {
         })
     }
 
+    #[classmethod]
+    fn from_points(_cls: &Bound<'_, PyType>, points: &Bound<PyAny>) -> PyResult<Region> {
+        let points = extract_points(points)?;
+        if points.is_empty() {
+            return Err(PyValueError::new_err("At least one point expected"));
+        }
+        let min_x = points.iter().map(|p| p.0).min().unwrap();
+        let max_x = points.iter().map(|p| p.0).max().unwrap();
+        let min_y = points.iter().map(|p| p.1).min().unwrap();
+        let max_y = points.iter().map(|p| p.1).max().unwrap();
+        Ok(Region {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x + 1,
+            height: max_y - min_y + 1,
+        })
+    }
+
     #[classmethod]
     fn from_corners(_cls: &Bound<'_, PyType>, x1: i32, y1: i32, x2: i32, y2: i32) -> Region {
         Region {
@@ -505,8 +1034,38 @@ impl Region {
         offset: &Bound<PyAny>,
         size: &Bound<PyAny>,
     ) -> PyResult<Region> {
-        let (x, y) = extract_integer_pair(offset)?;
-        let (width, height) = extract_integer_pair(size)?;
+        let (x, y) = extract_offset(offset)?;
+        let (width, height) = extract_size(size)?;
+        Ok(Region {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    #[classmethod]
+    fn from_dict(_cls: &Bound<'_, PyType>, values: &Bound<PyDict>) -> PyResult<Region> {
+        const KEYS: [&str; 4] = ["x", "y", "width", "height"];
+        let mut fields = [0; 4];
+        for (field, key) in fields.iter_mut().zip(KEYS) {
+            let Some(value) = values.get_item(key)? else {
+                return Err(PyKeyError::new_err(key));
+            };
+            *field = value.extract()?;
+        }
+        for key in values.keys() {
+            if !KEYS
+                .iter()
+                .any(|expected| key.eq(*expected).unwrap_or(false))
+            {
+                return Err(PyValueError::new_err(format!(
+                    "Unexpected key {}, expected 'x', 'y', 'width', and 'height'",
+                    key.repr()?
+                )));
+            }
+        }
+        let [x, y, width, height] = fields;
         Ok(Region {
             x,
             y,
@@ -515,32 +1074,53 @@ impl Region {
         })
     }
 
+    fn as_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let values = PyDict::new(py);
+        values.set_item("x", self.x)?;
+        values.set_item("y", self.y)?;
+        values.set_item("width", self.width)?;
+        values.set_item("height", self.height)?;
+        Ok(values)
+    }
+
     #[classmethod]
-    #[pyo3(signature=(window_region, region, *, top = false))]
+    #[pyo3(signature=(window_region, region, *, top = false, overscroll = 0))]
     pub fn get_scroll_to_visible(
         _cls: &Bound<'_, PyType>,
         window_region: &Region,
         region: &Region,
         top: bool,
+        overscroll: i32,
     ) -> GeometryOffset {
-        if !top && window_region.contains_region(region) {
+        let (window_left, window_top, window_right, window_bottom) = window_region.corners();
+        let region = region._crop_size(window_region.size()._as_tuple());
+        // Overscroll reveals cells either side of the region, but never more than the
+        // window has room for, or the region itself could be scrolled out of view.
+        let overscroll_x = overscroll.clamp(0, (window_region.width - region.width).max(0) / 2);
+        let overscroll_y = overscroll.clamp(0, (window_region.height - region.height).max(0) / 2);
+        let left = region.x - overscroll_x;
+        let top_ = region.y - overscroll_y;
+        let right = region.x + region.width + overscroll_x;
+        let bottom = region.y + region.height + overscroll_y;
+
+        if !top
+            && window_left <= left
+            && right <= window_right
+            && window_top <= top_
+            && bottom <= window_bottom
+        {
             // Region is already inside the window, so no need to move it.
             return GeometryOffset { x: 0, y: 0 };
         }
 
-        let (window_left, window_top, window_right, window_bottom) = window_region.corners();
-        let region = region._crop_size(window_region.size()._as_tuple());
-        let (left, top_, right, bottom) = region.corners();
         let mut delta_x = 0;
         let mut delta_y = 0;
 
-        if !((window_right > left && left >= window_left)
-            && (window_right > right && right >= window_left))
-        {
+        if !(window_left <= left && right <= window_right) {
             // The region does not fit
             // The window needs to scroll on the X axis to bring region into view
             let option1 = left - window_left;
-            let option2 = left - (window_right - region.width);
+            let option2 = right - window_right;
             delta_x = if option1.abs() < option2.abs() {
                 option1
             } else {
@@ -550,12 +1130,10 @@ impl Region {
 
         if top {
             delta_y = top_ - window_top;
-        } else if !((window_bottom > top_ && top_ >= window_top)
-            && (window_bottom > bottom && bottom >= window_top))
-        {
+        } else if !(window_top <= top_ && bottom <= window_bottom) {
             // The window needs to scroll on the Y axis to bring region into view
             let option1 = top_ - window_top;
-            let option2 = top_ - (window_bottom - region.height);
+            let option2 = bottom - window_bottom;
             delta_y = if option1.abs() < option2.abs() {
                 option1
             } else {
@@ -569,6 +1147,28 @@ impl Region {
         }
     }
 
+    #[classmethod]
+    pub fn scrollbar_thumb(
+        _cls: &Bound<'_, PyType>,
+        window_size: i32,
+        content_size: i32,
+        position: i32,
+    ) -> (i32, i32) {
+        if window_size <= 0 {
+            return (0, 0);
+        }
+        if content_size <= window_size {
+            // Everything is visible, so the thumb fills the track
+            return (0, window_size);
+        }
+        let (window, content) = (window_size as i64, content_size as i64);
+        let thumb_size = (window * window / content).max(1);
+        let max_position = content - window;
+        let position = (position as i64).clamp(0, max_position);
+        let thumb_offset = (window - thumb_size) * position / max_position;
+        (thumb_offset as i32, thumb_size as i32)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Region(x={}, y={}, width={}, height={})",
@@ -591,12 +1191,41 @@ impl Region {
         4
     }
 
+    fn count(&self, value: &Bound<PyAny>) -> usize {
+        count_value(&[self.x, self.y, self.width, self.height], value)
+    }
+
+    fn index(&self, value: &Bound<PyAny>) -> PyResult<usize> {
+        index_value(&[self.x, self.y, self.width, self.height], value)
+    }
+
     fn __bool__(&self) -> bool {
         return self.width * self.height > 0;
     }
 
+    /// The operation depends on the type of the right hand side:
+    ///
+    /// - `Size` grows the region, adding to its width and height (the origin is unchanged).
+    /// - `Offset` or a tuple of `(int, int)` translates the region.
+    ///
+    /// A `Size` is checked first since it is also a pair of integers. Anything else raises
+    /// `TypeError`. `__sub__` dispatches the same way, so `region + size - size == region`.
     fn __add__(&self, rhs: &Bound<PyAny>) -> PyResult<Region> {
-        let (x, y) = extract_integer_pair(rhs)?;
+        if let Ok(size) = rhs.extract::<Size>() {
+            return Ok(Region {
+                x: self.x,
+                y: self.y,
+                width: self.width + size.width,
+                height: self.height + size.height,
+            });
+        }
+        let (x, y) = if let Ok(offset) = rhs.extract::<GeometryOffset>() {
+            (offset.x, offset.y)
+        } else {
+            extract_integer_pair(rhs).map_err(|_| {
+                PyTypeError::new_err("Expected Offset, Size, or tuple of (int, int)")
+            })?
+        };
         Ok(Region {
             x: self.x + x,
             y: self.y + y,
@@ -605,8 +1234,24 @@ impl Region {
         })
     }
 
+    /// The inverse of `__add__`: a `Size` shrinks the region (dimensions don't go below 0),
+    /// while an `Offset` or a tuple of `(int, int)` translates it the other way.
     fn __sub__(&self, rhs: &Bound<PyAny>) -> PyResult<Region> {
-        let (x, y) = extract_integer_pair(rhs)?;
+        if let Ok(size) = rhs.extract::<Size>() {
+            return Ok(Region {
+                x: self.x,
+                y: self.y,
+                width: (self.width - size.width).max(0),
+                height: (self.height - size.height).max(0),
+            });
+        }
+        let (x, y) = if let Ok(offset) = rhs.extract::<GeometryOffset>() {
+            (offset.x, offset.y)
+        } else {
+            extract_integer_pair(rhs).map_err(|_| {
+                PyTypeError::new_err("Expected Offset, Size, or tuple of (int, int)")
+            })?
+        };
         Ok(Region {
             x: self.x - x,
             y: self.y - y,
@@ -624,6 +1269,21 @@ impl Region {
         }
     }
 
+    #[pyo3(signature=(other, clamp=false))]
+    fn get_margin_to(&self, other: &Region, clamp: bool) -> Spacing {
+        let spacing = self.get_spacing_between(other);
+        if clamp {
+            Spacing {
+                top: spacing.top.max(0),
+                right: spacing.right.max(0),
+                bottom: spacing.bottom.max(0),
+                left: spacing.left.max(0),
+            }
+        } else {
+            spacing
+        }
+    }
+
     #[getter]
     fn column_span(&self) -> (i32, i32) {
         (self.x, self.x + self.width)
@@ -649,6 +1309,11 @@ impl Region {
         self.width * self.height
     }
 
+    #[getter]
+    fn cell_count(&self) -> i64 {
+        self.width as i64 * self.height as i64
+    }
+
     #[getter]
     fn offset(&self) -> GeometryOffset {
         GeometryOffset {
@@ -704,9 +1369,26 @@ impl Region {
     }
 
     #[getter]
-    fn size(&self) -> Size {
-        Size {
-            width: self.width,
+    fn corner_offsets(
+        &self,
+    ) -> (
+        GeometryOffset,
+        GeometryOffset,
+        GeometryOffset,
+        GeometryOffset,
+    ) {
+        (
+            self.offset(),
+            self.top_right(),
+            self.bottom_left(),
+            self.bottom_right(),
+        )
+    }
+
+    #[getter]
+    fn size(&self) -> Size {
+        Size {
+            width: self.width,
             height: self.height,
         }
     }
@@ -734,6 +1416,31 @@ impl Region {
         Ok(range.into())
     }
 
+    fn iter_visible_lines<'py>(
+        &self,
+        py: Python<'py>,
+        viewport: &Region,
+    ) -> PyResult<Bound<'py, PyIterator>> {
+        let (start, end) = if self.overlaps(viewport) {
+            let visible = self.intersection(viewport);
+            (visible.y, visible.bottom())
+        } else {
+            (0, 0)
+        };
+        let range = PyRange::new(py, start as isize, end as isize)?;
+        PyIterator::from_object(&range)
+    }
+
+    #[getter]
+    fn transpose(&self) -> Self {
+        Region {
+            x: self.y,
+            y: self.x,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
     #[getter]
     fn reset_offset(&self) -> Self {
         Region {
@@ -744,8 +1451,13 @@ impl Region {
         }
     }
 
+    #[getter]
+    fn at_origin(&self) -> Self {
+        self.reset_offset()
+    }
+
     fn at_offset(&self, offset: &Bound<PyAny>) -> PyResult<Region> {
-        let (x, y) = extract_integer_pair(offset)?;
+        let (x, y) = extract_offset(offset)?;
         Ok(Region {
             x: x,
             y: y,
@@ -754,9 +1466,107 @@ impl Region {
         })
     }
 
+    fn with_center(&self, point: &Bound<PyAny>) -> PyResult<Region> {
+        let (x, y) = extract_offset(point)?;
+        Ok(Region {
+            x: (x as f64 - self.width as f64 / 2.0).floor() as i32,
+            y: (y as f64 - self.height as f64 / 2.0).floor() as i32,
+            width: self.width,
+            height: self.height,
+        })
+    }
+
+    fn move_to(&self, offset: &Bound<PyAny>) -> PyResult<Region> {
+        self.at_offset(offset)
+    }
+
+    fn with_offset(&self, offset: &Bound<PyAny>) -> PyResult<Region> {
+        self.at_offset(offset)
+    }
+
+    #[pyo3(signature=(width, height=None))]
+    fn resize(&self, width: &Bound<PyAny>, height: Option<i32>) -> PyResult<Region> {
+        let (width, height) = if let Some(height) = height {
+            (width.extract::<i32>()?, height)
+        } else {
+            extract_size(width)?
+        };
+        Ok(Region {
+            x: self.x,
+            y: self.y,
+            width: width.max(0),
+            height: height.max(0),
+        })
+    }
+
+    #[pyo3(signature=(x_factor, y_factor, *, round="floor"))]
+    fn scale(&self, x_factor: f64, y_factor: f64, round: &str) -> PyResult<Region> {
+        Ok(Region {
+            x: round_to_int(self.x as f64 * x_factor, round)?,
+            y: round_to_int(self.y as f64 * y_factor, round)?,
+            width: round_to_int(self.width as f64 * x_factor, round)?,
+            height: round_to_int(self.height as f64 * y_factor, round)?,
+        })
+    }
+
+    /// Scale by float factors, rounding the edges (rather than the size) to whole cells.
+    #[pyo3(signature=(x_factor, y_factor, *, round="expand"))]
+    fn quantize(&self, x_factor: f64, y_factor: f64, round: &str) -> PyResult<Region> {
+        let (start_mode, end_mode) = match round {
+            "expand" => ("floor", "ceil"),
+            "contract" => ("ceil", "floor"),
+            "nearest" => ("round", "round"),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid rounding mode {:?}, expected 'expand', 'contract', or 'nearest'",
+                    round
+                )))
+            }
+        };
+        let x = round_to_int(self.x as f64 * x_factor, start_mode)?;
+        let y = round_to_int(self.y as f64 * y_factor, start_mode)?;
+        let right = round_to_int(self.right() as f64 * x_factor, end_mode)?;
+        let bottom = round_to_int(self.bottom() as f64 * y_factor, end_mode)?;
+        Ok(Region {
+            x,
+            y,
+            width: (right - x).max(0),
+            height: (bottom - y).max(0),
+        })
+    }
+
     fn crop_size(&self, size: &Bound<PyAny>) -> PyResult<Region> {
-        let size = extract_integer_pair(size)?;
-        Ok(self._crop_size(size))
+        let (width, height) = extract_size(size)?;
+        Ok(self._crop_size((width.max(0), height.max(0))))
+    }
+
+    /// Return the equivalent region with the corners swapped so width and height are
+    /// non-negative. The constructor already does this; regions built by arithmetic may not.
+    fn normalize(&self) -> Region {
+        self._normalize()
+    }
+
+    fn _normalize(&self) -> Region {
+        let Region {
+            mut x,
+            mut y,
+            mut width,
+            mut height,
+        } = *self;
+        if width < 0 {
+            x += width;
+            width = -width;
+        }
+        if height < 0 {
+            y += height;
+            height = -height;
+        }
+        Region {
+            x,
+            y,
+            width,
+            height,
+        }
     }
 
     fn _crop_size(&self, size: (i32, i32)) -> Region {
@@ -779,16 +1589,46 @@ impl Region {
     }
 
     fn overlaps(&self, other: &Region) -> bool {
+        // Regions with no area never overlap, which keeps this symmetric
+        if self.width <= 0 || self.height <= 0 || other.width <= 0 || other.height <= 0 {
+            return false;
+        }
         let (x, y, x2, y2) = self.corners();
         let (ox, oy, ox2, oy2) = other.corners();
-        return ((x2 > ox && ox >= x) || (x2 > ox2 && ox2 > x) || (ox < x && ox2 >= x2))
-            && ((y2 > oy && oy >= y) || (y2 > oy2 && oy2 > y) || (oy < y && oy2 >= y2));
+        x < ox2 && ox < x2 && y < oy2 && oy < y2
+    }
+
+    fn touches(&self, other: &Region) -> bool {
+        // True if the regions share an edge segment, or overlap
+        if self.width <= 0 || self.height <= 0 || other.width <= 0 || other.height <= 0 {
+            return false;
+        }
+        let overlap_x = self.right().min(other.right()) - self.x.max(other.x);
+        let overlap_y = self.bottom().min(other.bottom()) - self.y.max(other.y);
+        overlap_x >= 0 && overlap_y >= 0 && (overlap_x > 0 || overlap_y > 0)
+    }
+
+    fn gap_to(&self, other: &Region) -> i32 {
+        // Chebyshev distance: the larger of the horizontal and vertical gaps
+        let gap_x = (other.x - self.right()).max(self.x - other.right()).max(0);
+        let gap_y = (other.y - self.bottom())
+            .max(self.y - other.bottom())
+            .max(0);
+        gap_x.max(gap_y)
     }
 
     fn contains(&self, x: i32, y: i32) -> bool {
         self.x + self.width > x && x >= self.x && self.y + self.height > y && y >= self.y
     }
 
+    fn contains_x(&self, x: i32) -> bool {
+        self.x + self.width > x && x >= self.x
+    }
+
+    fn contains_y(&self, y: i32) -> bool {
+        self.y + self.height > y && y >= self.y
+    }
+
     fn contains_point(&self, point: &Bound<PyAny>) -> PyResult<bool> {
         if let Ok((x, y)) = point.extract::<(i32, i32)>() {
             Ok(self.contains(x, y))
@@ -799,13 +1639,67 @@ impl Region {
         }
     }
 
-    fn contains_region(&self, other: &Region) -> bool {
+    fn clip_line(&self, y: i32, x1: i32, x2: i32) -> Option<(i32, i32)> {
+        if y < self.y || y >= self.bottom() {
+            return None;
+        }
+        let start = x1.max(self.x);
+        let end = x2.min(self.right());
+        if start >= end {
+            return None;
+        }
+        Some((start, end))
+    }
+
+    fn same_size(&self, other: &Bound<PyAny>) -> PyResult<bool> {
+        let (width, height) = if let Ok(region) = other.extract::<Region>() {
+            (region.width, region.height)
+        } else {
+            extract_size(other)?
+        };
+        Ok(self.width == width && self.height == height)
+    }
+
+    #[pyo3(signature=(other, *, strict=false))]
+    fn contains_region(&self, other: &Region, strict: bool) -> bool {
         let (x1, y1, x2, y2) = self.corners();
         let (ox, oy, ox2, oy2) = other.corners();
-        return (x2 >= ox && ox >= x1)
-            && (y2 >= oy && oy >= y1)
-            && (x2 >= ox2 && ox2 >= x1)
-            && (y2 >= oy2 && oy2 >= y1);
+        if strict {
+            (x2 > ox && ox > x1)
+                && (y2 > oy && oy > y1)
+                && (x2 > ox2 && ox2 > x1)
+                && (y2 > oy2 && oy2 > y1)
+        } else {
+            (x2 >= ox && ox >= x1)
+                && (y2 >= oy && oy >= y1)
+                && (x2 >= ox2 && ox2 >= x1)
+                && (y2 >= oy2 && oy2 >= y1)
+        }
+    }
+
+    fn inside(&self, container: &Region) -> bool {
+        container.contains_region(self, false)
+    }
+
+    fn clamp_offset(&self, offset: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        let (x, y) = extract_offset(offset)?;
+        Ok(GeometryOffset {
+            x: x.clamp(self.x, self.x + (self.width - 1).max(0)),
+            y: y.clamp(self.y, self.y + (self.height - 1).max(0)),
+        })
+    }
+
+    fn wrap_offset(&self, offset: &Bound<PyAny>) -> PyResult<GeometryOffset> {
+        if self.width <= 0 || self.height <= 0 {
+            return Err(PyValueError::new_err(
+                "Can't wrap an offset in a region with no area",
+            ));
+        }
+        let (x, y) = extract_offset(offset)?;
+        Ok(GeometryOffset {
+            x: self.x + (x - self.x).rem_euclid(self.width),
+            y: self.y + (y - self.y).rem_euclid(self.height),
+        })
     }
 
     fn translate(&self, offset: &Bound<PyAny>) -> PyResult<Region> {
@@ -820,7 +1714,7 @@ impl Region {
 
     fn __contains__(&self, rhs: &Bound<PyAny>) -> bool {
         if let Ok(region) = rhs.extract::<Region>() {
-            self.contains_region(&region)
+            self.contains_region(&region, false)
         } else if let Ok((x, y)) = rhs.extract::<(i32, i32)>() {
             self.contains(x, y)
         } else if let Ok(GeometryOffset { x, y }) = rhs.extract::<GeometryOffset>() {
@@ -830,29 +1724,40 @@ impl Region {
         }
     }
 
-    fn clip(&self, width: i32, height: i32) -> Region {
+    fn clip(&self, width: i32, height: i32) -> PyResult<Region> {
+        if width < 0 || height < 0 {
+            return Err(PyValueError::new_err(
+                "Clip width and height must not be negative",
+            ));
+        }
         let (x1, y1, x2, y2) = self.corners();
         let x = clamp(x1, 0, width);
         let y = clamp(y1, 0, height);
-        Region {
+        Ok(Region {
             x,
             y,
             width: clamp(x2, 0, width) - x,
             height: clamp(y2, 0, height) - y,
-        }
+        })
+    }
+
+    fn clip_checked(&self, width: i32, height: i32) -> PyResult<(Region, bool)> {
+        let clipped = self.clip(width, height)?;
+        let changed = !clipped.__eq__(self);
+        Ok((clipped, changed))
     }
 
     fn grow(&self, margin: &Bound<PyAny>) -> PyResult<Region> {
         let grow_margin = extract_integer_quad(margin)?;
         if grow_margin == (0, 0, 0, 0) {
-            return Ok(self.clone());
+            return Ok(*self);
         }
         Ok(self._grow(grow_margin))
     }
 
     fn _grow(&self, margin: (i32, i32, i32, i32)) -> Region {
         if margin == (0, 0, 0, 0) {
-            return self.clone();
+            return *self;
         }
         let (top, right, bottom, left) = margin;
         let Region {
@@ -869,17 +1774,29 @@ impl Region {
         }
     }
 
-    fn shrink(&self, margin: &Bound<PyAny>) -> PyResult<Region> {
+    #[pyo3(signature=(margin, *, minimum=None))]
+    fn shrink(&self, margin: &Bound<PyAny>, minimum: Option<&Bound<PyAny>>) -> PyResult<Region> {
         let shrink_margin = extract_integer_quad(margin)?;
-        if shrink_margin == (0, 0, 0, 0) {
-            return Ok(self.clone());
-        }
-        Ok(self._shrink(shrink_margin))
+        let region = if shrink_margin == (0, 0, 0, 0) {
+            *self
+        } else {
+            self._shrink(shrink_margin)
+        };
+        let Some(minimum) = minimum else {
+            return Ok(region);
+        };
+        let (minimum_width, minimum_height) = extract_size(minimum)?;
+        Ok(Region {
+            x: region.x,
+            y: region.y,
+            width: region.width.max(minimum_width),
+            height: region.height.max(minimum_height),
+        })
     }
 
     fn _shrink(&self, margin: (i32, i32, i32, i32)) -> Region {
         if margin == (0, 0, 0, 0) {
-            return self.clone();
+            return *self;
         }
         let (top, right, bottom, left) = margin;
         let Region {
@@ -896,6 +1813,67 @@ impl Region {
         }
     }
 
+    fn inset(&self, fraction: f64) -> Region {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 0.5)
+        };
+        let inset_x = (self.width as f64 * fraction).floor() as i32;
+        let inset_y = (self.height as f64 * fraction).floor() as i32;
+        self._shrink((inset_y, inset_x, inset_y, inset_x))
+    }
+
+    #[pyo3(signature = (border=1))]
+    fn inner(&self, border: i32) -> Region {
+        // A negative border is treated as no border, as in border_regions
+        let border = border.max(0);
+        self._shrink((border, border, border, border))
+    }
+
+    fn expand_collapse(&self, margin: &Bound<PyAny>) -> PyResult<(Region, Region)> {
+        let margin = extract_integer_quad(margin)?;
+        Ok((self._grow(margin), self._shrink(margin)))
+    }
+
+    #[pyo3(signature = (width=1))]
+    fn border_regions(&self, width: i32) -> (Region, Region, Region, Region) {
+        // Top and bottom strips span the full width, left and right fill the space between
+        let (region_width, region_height) = (self.width.max(0), self.height.max(0));
+        let border = width.max(0);
+        let top_height = border.min(region_height);
+        let bottom_height = border.min(region_height - top_height);
+        let left_width = border.min(region_width);
+        let right_width = border.min(region_width - left_width);
+        let side_height = region_height - top_height - bottom_height;
+        (
+            Region {
+                x: self.x,
+                y: self.y,
+                width: region_width,
+                height: top_height,
+            },
+            Region {
+                x: self.x + region_width - right_width,
+                y: self.y + top_height,
+                width: right_width,
+                height: side_height,
+            },
+            Region {
+                x: self.x,
+                y: self.y + region_height - bottom_height,
+                width: region_width,
+                height: bottom_height,
+            },
+            Region {
+                x: self.x,
+                y: self.y + top_height,
+                width: left_width,
+                height: side_height,
+            },
+        )
+    }
+
     fn intersection(&self, region: &Region) -> Region {
         let (x1, y1, w1, h1) = (self.x, self.y, self.width, self.height);
         let (cx1, cy1, w2, h2) = (region.x, region.y, region.width, region.height);
@@ -941,6 +1919,66 @@ impl Region {
         }
     }
 
+    fn _subtract(&self, region: &Region) -> Vec<Region> {
+        let clip = self.intersection(region);
+        if clip.width <= 0 || clip.height <= 0 {
+            return if self.width > 0 && self.height > 0 {
+                vec![*self]
+            } else {
+                vec![]
+            };
+        }
+        [
+            Region {
+                x: self.x,
+                y: self.y,
+                width: self.width,
+                height: clip.y - self.y,
+            },
+            Region {
+                x: self.x,
+                y: clip.y,
+                width: clip.x - self.x,
+                height: clip.height,
+            },
+            Region {
+                x: clip.right(),
+                y: clip.y,
+                width: self.right() - clip.right(),
+                height: clip.height,
+            },
+            Region {
+                x: self.x,
+                y: clip.bottom(),
+                width: self.width,
+                height: self.bottom() - clip.bottom(),
+            },
+        ]
+        .into_iter()
+        .filter(|piece| piece.width > 0 && piece.height > 0)
+        .collect()
+    }
+
+    fn symmetric_difference(&self, region: &Region) -> Vec<Region> {
+        let mut pieces = self._subtract(region);
+        pieces.extend(region._subtract(self));
+        pieces
+    }
+
+    fn clamp_to(&self, container: &Region) -> Region {
+        let clipped = self.intersection(container);
+        Region {
+            x: clipped.x,
+            y: clipped.y,
+            width: clipped.width.max(0),
+            height: clipped.height.max(0),
+        }
+    }
+
+    fn clip_to(&self, region: &Region) -> Region {
+        self.clamp_to(region)
+    }
+
     fn union(&self, region: &Region) -> Region {
         let (x1, y1, x2, y2) = self.corners();
         let (ox1, oy1, ox2, oy2) = region.corners();
@@ -954,6 +1992,26 @@ impl Region {
         }
     }
 
+    fn expand_to_include(&self, other: &Bound<PyAny>) -> PyResult<Region> {
+        if let Ok(region) = other.extract::<Region>() {
+            return Ok(self.union(&region));
+        }
+        let (x, y) = extract_offset(other)?;
+        Ok(self.union(&Region {
+            x,
+            y,
+            width: 1,
+            height: 1,
+        }))
+    }
+
+    fn union_size(&self, region: &Region) -> Size {
+        Size {
+            width: self.right().max(region.right()) - self.x.min(region.x),
+            height: self.bottom().max(region.bottom()) - self.y.min(region.y),
+        }
+    }
+
     fn split(&self, mut cut_x: i32, mut cut_y: i32) -> (Region, Region, Region, Region) {
         let Region {
             x,
@@ -968,6 +2026,8 @@ impl Region {
         if cut_y < 0 {
             cut_y = height + cut_y;
         }
+        let cut_x = cut_x.clamp(0, width.max(0));
+        let cut_y = cut_y.clamp(0, height.max(0));
         (
             Region {
                 x: x,
@@ -1006,6 +2066,7 @@ impl Region {
         if cut < 0 {
             cut = height + cut;
         }
+        let cut = cut.clamp(0, height.max(0));
         (
             Region {
                 x: x,
@@ -1032,6 +2093,7 @@ impl Region {
         if cut < 0 {
             cut = width + cut;
         }
+        let cut = cut.clamp(0, width.max(0));
         (
             Region {
                 x,
@@ -1048,6 +2110,213 @@ impl Region {
         )
     }
 
+    fn split_at_columns(&self, cuts: Vec<i32>) -> PyResult<Vec<Region>> {
+        let width = self.width.max(0);
+        let mut pieces = Vec::with_capacity(cuts.len() + 1);
+        let mut previous = 0;
+        for cut in cuts.into_iter().chain([width]) {
+            if cut < previous || cut > width {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid cut {}, expected sorted cuts between 0 and {}",
+                    cut, width
+                )));
+            }
+            pieces.push(Region {
+                x: self.x + previous,
+                y: self.y,
+                width: cut - previous,
+                height: self.height,
+            });
+            previous = cut;
+        }
+        Ok(pieces)
+    }
+
+    fn points(&self) -> RegionPoints {
+        RegionPoints {
+            region: *self,
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    fn iter_lines(&self) -> RegionLines {
+        RegionLines {
+            region: *self,
+            y: self.y,
+        }
+    }
+
+    #[pyo3(signature=(size, horizontal="left", vertical="top"))]
+    fn align_size(
+        &self,
+        size: &Bound<PyAny>,
+        horizontal: &str,
+        vertical: &str,
+    ) -> PyResult<Region> {
+        let (width, height) = extract_size(size)?;
+        let x = match horizontal {
+            "left" => self.x,
+            "center" => self.x + (self.width - width).div_euclid(2),
+            "right" => self.right() - width,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid horizontal alignment {:?}, expected 'left', 'center', or 'right'",
+                    horizontal
+                )))
+            }
+        };
+        let y = match vertical {
+            "top" => self.y,
+            "middle" => self.y + (self.height - height).div_euclid(2),
+            "bottom" => self.bottom() - height,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid vertical alignment {:?}, expected 'top', 'middle', or 'bottom'",
+                    vertical
+                )))
+            }
+        };
+        Ok(Region {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    fn align_to(&self, container: &Region, edge: &str) -> PyResult<Region> {
+        let (x, y) = match edge {
+            "top" => (self.x, container.y),
+            "bottom" => (self.x, container.bottom() - self.height),
+            "left" => (container.x, self.y),
+            "right" => (container.right() - self.width, self.y),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid edge {:?}, expected 'top', 'bottom', 'left', or 'right'",
+                    edge
+                )))
+            }
+        };
+        Ok(Region {
+            x,
+            y,
+            width: self.width,
+            height: self.height,
+        })
+    }
+
+    fn best_side(&self, anchor: &Region, container: &Region) -> &'static str {
+        // Room left over on each side once this region is placed there; ties favour earlier sides
+        let sides = [
+            ("above", anchor.y - container.y - self.height),
+            ("below", container.bottom() - anchor.bottom() - self.height),
+            ("left", anchor.x - container.x - self.width),
+            ("right", container.right() - anchor.right() - self.width),
+        ];
+        let mut best = sides[0];
+        for side in &sides[1..] {
+            if side.1 > best.1 {
+                best = *side;
+            }
+        }
+        best.0
+    }
+
+    fn place_center(&self, size: &Bound<PyAny>) -> PyResult<Region> {
+        self.align_size(size, "center", "middle")
+    }
+
+    fn tile(&self, container: &Region) -> PyResult<Vec<Region>> {
+        if self.width <= 0 || self.height <= 0 {
+            return Err(PyValueError::new_err("Cell dimensions must be positive"));
+        }
+        let mut tiles = Vec::new();
+        for y in (container.y..container.bottom()).step_by(self.height as usize) {
+            for x in (container.x..container.right()).step_by(self.width as usize) {
+                tiles.push(Region {
+                    x,
+                    y,
+                    width: self.width.min(container.right() - x),
+                    height: self.height.min(container.bottom() - y),
+                });
+            }
+        }
+        Ok(tiles)
+    }
+
+    #[pyo3(signature=(columns, rows, gutter=None))]
+    fn divide(
+        &self,
+        columns: i32,
+        rows: i32,
+        gutter: Option<&Bound<PyAny>>,
+    ) -> PyResult<Vec<Region>> {
+        if columns <= 0 || rows <= 0 {
+            return Err(PyValueError::new_err("Columns and rows must be positive"));
+        }
+        let (h_gutter, v_gutter) = match gutter {
+            None => (0, 0),
+            Some(gutter) => match gutter.extract::<i32>() {
+                Ok(gutter) => (gutter, gutter),
+                _ => extract_integer_pair(gutter)?,
+            },
+        };
+        if h_gutter < 0 || v_gutter < 0 {
+            return Err(PyValueError::new_err("Gutter must not be negative"));
+        }
+        let column_spans = divide_span(self.x, self.width, columns, h_gutter);
+        let row_spans = divide_span(self.y, self.height, rows, v_gutter);
+        let mut cells = Vec::with_capacity((columns * rows) as usize);
+        for &(y, height) in &row_spans {
+            for &(x, width) in &column_spans {
+                cells.push(Region {
+                    x,
+                    y,
+                    width,
+                    height,
+                });
+            }
+        }
+        Ok(cells)
+    }
+
+    fn fit_columns(&self, weights: Vec<i32>) -> PyResult<Vec<Region>> {
+        Ok(weighted_spans(self.x, self.width, &weights)?
+            .into_iter()
+            .map(|(x, width)| Region {
+                x,
+                y: self.y,
+                width,
+                height: self.height,
+            })
+            .collect())
+    }
+
+    fn fit_rows(&self, weights: Vec<i32>) -> PyResult<Vec<Region>> {
+        Ok(weighted_spans(self.y, self.height, &weights)?
+            .into_iter()
+            .map(|(y, height)| Region {
+                x: self.x,
+                y,
+                width: self.width,
+                height,
+            })
+            .collect())
+    }
+
+    fn center_region(&self, size: &Bound<PyAny>) -> PyResult<Region> {
+        Ok(self.place_center(size)?.translate_inside(self, true, true))
+    }
+
+    fn iter_grid(&self, columns: i32, rows: i32) -> PyResult<RegionGrid> {
+        Ok(RegionGrid {
+            cells: self.divide(columns, rows, None)?,
+            columns: columns as usize,
+            index: 0,
+        })
+    }
+
     #[pyo3(signature=(container, x_axis=true, y_axis=true))]
     fn translate_inside(&self, container: &Region, x_axis: bool, y_axis: bool) -> Region {
         let Region {
@@ -1078,6 +2347,10 @@ impl Region {
         }
     }
 
+    /// Move the region to the opposite side of its current position on each non-zero axis.
+    ///
+    /// The gap between the two positions is the larger of the margin's edges on that axis
+    /// (`max_width` or `max_height`), as adjacent margins collapse. This matches Textual.
     #[pyo3(signature = (x_axis=1, y_axis=1, margin=None))]
     fn inflect(&self, x_axis: i32, y_axis: i32, margin: Option<Spacing>) -> Region {
         let inflect_margin = margin.unwrap_or(Spacing {
@@ -1163,36 +2436,270 @@ impl Region {
     }
 }
 
-enum SpacingDimensions {
-    Single(i32),
-    Tuple1(i32),
-    Tuple2(i32, i32),
-    Tuple4(i32, i32, i32, i32),
+fn extract_points(points: &Bound<PyAny>) -> PyResult<Vec<(i32, i32)>> {
+    let mut extracted = Vec::new();
+    for item in PyIterator::from_object(points)? {
+        extracted.push(extract_offset(&item?)?);
+    }
+    Ok(extracted)
 }
 
-#[pyclass(frozen)]
-#[derive(Debug, Clone, Copy)]
-pub struct Spacing {
-    #[pyo3(get)]
-    pub top: i32,
-    #[pyo3(get)]
-    pub right: i32,
-    #[pyo3(get)]
-    pub bottom: i32,
-    #[pyo3(get)]
-    pub left: i32,
+#[pyfunction]
+pub fn distances(py: Python, origin: &Bound<PyAny>, points: &Bound<PyAny>) -> PyResult<Vec<f64>> {
+    let (x, y) = extract_offset(origin)?;
+    let points = extract_points(points)?;
+    Ok(py.detach(|| {
+        points
+            .iter()
+            .map(|&(point_x, point_y)| {
+                let dx = point_x as f64 - x as f64;
+                let dy = point_y as f64 - y as f64;
+                (dx * dx + dy * dy).sqrt()
+            })
+            .collect()
+    }))
 }
 
-#[pymethods]
+#[pyfunction]
+pub fn distances_squared(
+    py: Python,
+    origin: &Bound<PyAny>,
+    points: &Bound<PyAny>,
+) -> PyResult<Vec<i128>> {
+    let (x, y) = extract_offset(origin)?;
+    let points = extract_points(points)?;
+    Ok(py.detach(|| {
+        points
+            .iter()
+            .map(|&(point_x, point_y)| {
+                let dx = point_x as i128 - x as i128;
+                let dy = point_y as i128 - y as i128;
+                dx * dx + dy * dy
+            })
+            .collect()
+    }))
+}
+
+#[pyfunction]
+pub fn coalesce_regions(regions: &Bound<PyAny>) -> PyResult<Vec<Region>> {
+    let mut coalesced = Vec::new();
+    for item in PyIterator::from_object(regions)? {
+        coalesced.push(item?.extract::<Region>()?);
+    }
+
+    // Merge touching or overlapping pairs until no more merges are possible
+    let mut merged = true;
+    while merged {
+        merged = false;
+        'search: for i in 0..coalesced.len() {
+            for j in (i + 1)..coalesced.len() {
+                if coalesced[i].touches(&coalesced[j]) {
+                    coalesced[i] = coalesced[i].union(&coalesced[j]);
+                    coalesced.remove(j);
+                    merged = true;
+                    break 'search;
+                }
+            }
+        }
+    }
+    Ok(coalesced)
+}
+
+#[pyfunction]
+pub fn summarize_regions(regions: &Bound<PyAny>) -> PyResult<(Option<Region>, i64)> {
+    let mut bounds: Option<Region> = None;
+    let mut total_area = 0;
+    for item in PyIterator::from_object(regions)? {
+        let region = item?.extract::<Region>()?;
+        if region.width <= 0 || region.height <= 0 {
+            // Empty regions cover nothing, and shouldn't stretch the bounding box
+            continue;
+        }
+        total_area += region.cell_count();
+        bounds = Some(match bounds {
+            Some(bounds) => bounds.union(&region),
+            None => region,
+        });
+    }
+    Ok((bounds, total_area))
+}
+
+#[pyclass]
+pub struct OffsetLine {
+    x: i32,
+    y: i32,
+    end_x: i32,
+    end_y: i32,
+    dx: i64,
+    dy: i64,
+    step_x: i32,
+    step_y: i32,
+    error: i64,
+    done: bool,
+}
+
+#[pymethods]
+impl OffsetLine {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<GeometryOffset> {
+        if slf.done {
+            return None;
+        }
+        let offset = GeometryOffset { x: slf.x, y: slf.y };
+        if slf.x == slf.end_x && slf.y == slf.end_y {
+            slf.done = true;
+            return Some(offset);
+        }
+        // Bresenham step
+        let error2 = 2 * slf.error;
+        if error2 >= slf.dy {
+            slf.error += slf.dy;
+            slf.x += slf.step_x;
+        }
+        if error2 <= slf.dx {
+            slf.error += slf.dx;
+            slf.y += slf.step_y;
+        }
+        Some(offset)
+    }
+}
+
+#[pyclass]
+pub struct RegionPoints {
+    region: Region,
+    x: i32,
+    y: i32,
+}
+
+#[pymethods]
+impl RegionPoints {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<GeometryOffset> {
+        let region = slf.region;
+        if region.width <= 0 || slf.y >= region.bottom() {
+            return None;
+        }
+        let offset = GeometryOffset { x: slf.x, y: slf.y };
+        slf.x += 1;
+        if slf.x >= region.right() {
+            slf.x = region.x;
+            slf.y += 1;
+        }
+        Some(offset)
+    }
+}
+
+#[pyclass]
+pub struct RegionLines {
+    region: Region,
+    y: i32,
+}
+
+#[pymethods]
+impl RegionLines {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(i32, i32, i32)> {
+        let region = slf.region;
+        if slf.y >= region.bottom() {
+            return None;
+        }
+        let y = slf.y;
+        slf.y += 1;
+        Some((y, region.x, region.width))
+    }
+}
+
+#[pyclass]
+pub struct RegionGrid {
+    cells: Vec<Region>,
+    columns: usize,
+    index: usize,
+}
+
+#[pymethods]
+impl RegionGrid {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(usize, usize, Region)> {
+        let index = slf.index;
+        let cell = *slf.cells.get(index)?;
+        slf.index += 1;
+        Some((index / slf.columns, index % slf.columns, cell))
+    }
+}
+
+enum SpacingDimensions {
+    Single(i32),
+    Tuple1(i32),
+    Tuple2(i32, i32),
+    Tuple4(i32, i32, i32, i32),
+}
+
+#[pyclass(frozen)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spacing {
+    #[pyo3(get)]
+    pub top: i32,
+    #[pyo3(get)]
+    pub right: i32,
+    #[pyo3(get)]
+    pub bottom: i32,
+    #[pyo3(get)]
+    pub left: i32,
+}
+
+#[pymethods]
 impl Spacing {
     #[new]
-    #[pyo3(signature=(top=0, right=0, bottom=0, left=0))]
-    fn new(top: i32, right: i32, bottom: i32, left: i32) -> Spacing {
-        Spacing {
-            top,
-            right,
-            bottom,
-            left,
+    #[pyo3(signature=(top=None, right=None, bottom=None, left=None))]
+    fn new(
+        top: Option<&Bound<PyAny>>,
+        right: Option<i32>,
+        bottom: Option<i32>,
+        left: Option<i32>,
+    ) -> PyResult<Spacing> {
+        let single = right.is_none() && bottom.is_none() && left.is_none();
+        let right = right.unwrap_or(0);
+        let bottom = bottom.unwrap_or(0);
+        let left = left.unwrap_or(0);
+        let Some(top) = top else {
+            return Ok(Spacing {
+                top: 0,
+                right,
+                bottom,
+                left,
+            });
+        };
+        if let Ok(top) = top.extract::<i32>() {
+            Ok(Spacing {
+                top,
+                right,
+                bottom,
+                left,
+            })
+        } else if !single {
+            Err(PyTypeError::new_err("Expected int for top"))
+        } else if let Ok(spacing) = top.extract::<Spacing>() {
+            Ok(spacing)
+        } else {
+            let (top, right, bottom, left) = extract_integer_quad(top)?;
+            Ok(Spacing {
+                top,
+                right,
+                bottom,
+                left,
+            })
         }
     }
     fn __repr__(&self) -> String {
@@ -1217,6 +2724,14 @@ impl Spacing {
         4
     }
 
+    fn count(&self, value: &Bound<PyAny>) -> usize {
+        count_value(&[self.top, self.right, self.bottom, self.left], value)
+    }
+
+    fn index(&self, value: &Bound<PyAny>) -> PyResult<usize> {
+        index_value(&[self.top, self.right, self.bottom, self.left], value)
+    }
+
     fn __eq__(&self, rhs: &Spacing) -> bool {
         self.top == rhs.top
             && self.right == rhs.right
@@ -1238,7 +2753,14 @@ impl Spacing {
     }
 
     fn __add__(&self, rhs: &Bound<PyAny>) -> PyResult<Spacing> {
-        if let Ok((top, right, bottom, left)) = rhs.extract::<(i32, i32, i32, i32)>() {
+        if let Ok(amount) = rhs.extract::<i32>() {
+            Ok(Spacing {
+                top: self.top + amount,
+                right: self.right + amount,
+                bottom: self.bottom + amount,
+                left: self.left + amount,
+            })
+        } else if let Ok((top, right, bottom, left)) = rhs.extract::<(i32, i32, i32, i32)>() {
             Ok(Spacing {
                 top: self.top + top,
                 right: self.right + right,
@@ -1260,13 +2782,20 @@ impl Spacing {
             })
         } else {
             Err(PyTypeError::new_err(
-                "Expected tuple of (int, int, int, int)",
+                "Expected int or tuple of (int, int, int, int)",
             ))
         }
     }
 
     fn __sub__(&self, rhs: &Bound<PyAny>) -> PyResult<Spacing> {
-        if let Ok((top, right, bottom, left)) = rhs.extract::<(i32, i32, i32, i32)>() {
+        if let Ok(amount) = rhs.extract::<i32>() {
+            Ok(Spacing {
+                top: self.top - amount,
+                right: self.right - amount,
+                bottom: self.bottom - amount,
+                left: self.left - amount,
+            })
+        } else if let Ok((top, right, bottom, left)) = rhs.extract::<(i32, i32, i32, i32)>() {
             Ok(Spacing {
                 top: self.top - top,
                 right: self.right - right,
@@ -1288,11 +2817,30 @@ impl Spacing {
             })
         } else {
             Err(PyTypeError::new_err(
-                "Expected tuple of (int, int, int, int)",
+                "Expected int or tuple of (int, int, int, int)",
             ))
         }
     }
 
+    fn __radd__(&self, lhs: &Bound<PyAny>) -> PyResult<Spacing> {
+        self.__add__(lhs)
+    }
+
+    fn __rsub__(&self, lhs: &Bound<PyAny>) -> PyResult<Spacing> {
+        let Spacing {
+            top,
+            right,
+            bottom,
+            left,
+        } = self.__sub__(lhs)?;
+        Ok(Spacing {
+            top: -top,
+            right: -right,
+            bottom: -bottom,
+            left: -left,
+        })
+    }
+
     #[getter]
     fn width(&self) -> i32 {
         self.left + self.right
@@ -1328,6 +2876,27 @@ impl Spacing {
         (self.left + self.right, self.top + self.bottom)
     }
 
+    #[getter]
+    fn totals_size(&self) -> Size {
+        self.pad_size()
+    }
+
+    #[getter]
+    fn top_left_offset(&self) -> GeometryOffset {
+        GeometryOffset {
+            x: self.left,
+            y: self.top,
+        }
+    }
+
+    #[getter]
+    fn pad_size(&self) -> Size {
+        Size {
+            width: self.left + self.right,
+            height: self.top + self.bottom,
+        }
+    }
+
     fn __bool__(&self) -> bool {
         self.top != 0 || self.right != 0 || self.bottom != 0 || self.left != 0
     }
@@ -1451,6 +3020,25 @@ impl Spacing {
         }
     }
 
+    fn grow(&self, region: &Region) -> Region {
+        region._grow(self._as_tuple())
+    }
+
+    fn shrink(&self, region: &Region) -> Region {
+        region._shrink(self._as_tuple())
+    }
+
+    fn as_region(&self, size: &Bound<PyAny>) -> PyResult<Region> {
+        let (width, height) = extract_size(size)?;
+        let region = Region {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        };
+        Ok(region._grow(self._as_tuple()))
+    }
+
     fn grow_maximum(&self, other: &Spacing) -> Spacing {
         let Spacing {
             top,
@@ -1471,4 +3059,1892 @@ impl Spacing {
             left: left.max(other_left),
         }
     }
+
+    fn scale_clamped(&self, factor: f64, maximum: i32) -> Spacing {
+        let scale = |edge: i32| ((edge as f64 * factor).floor() as i32).min(maximum);
+        Spacing {
+            top: scale(self.top),
+            right: scale(self.right),
+            bottom: scale(self.bottom),
+            left: scale(self.left),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::IntoPyObjectExt;
+
+    fn region(x: i32, y: i32, width: i32, height: i32) -> Region {
+        Region {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    fn offset(x: i32, y: i32) -> GeometryOffset {
+        GeometryOffset { x, y }
+    }
+
+    fn size(width: i32, height: i32) -> Size {
+        Size { width, height }
+    }
+
+    fn spacing(top: i32, right: i32, bottom: i32, left: i32) -> Spacing {
+        Spacing {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    fn with_py<F: FnOnce(Python<'_>)>(f: F) {
+        Python::initialize();
+        Python::attach(f)
+    }
+
+    fn to_py<'py, T: IntoPyObject<'py>>(py: Python<'py>, value: T) -> Bound<'py, PyAny> {
+        value.into_bound_py_any(py).unwrap()
+    }
+
+    fn offsets(iterable: &Bound<PyAny>) -> Vec<GeometryOffset> {
+        iterable
+            .try_iter()
+            .unwrap()
+            .map(|item| item.unwrap().extract::<GeometryOffset>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_points() {
+        with_py(|py| {
+            let points = Bound::new(py, region(1, 2, 2, 2).points()).unwrap();
+            assert_eq!(
+                offsets(points.as_any()),
+                vec![offset(1, 2), offset(2, 2), offset(1, 3), offset(2, 3)]
+            );
+            for empty in [region(1, 2, 0, 2), region(1, 2, 2, 0)] {
+                let points = Bound::new(py, empty.points()).unwrap();
+                assert!(offsets(points.as_any()).is_empty());
+            }
+        });
+    }
+
+    #[test]
+    fn test_corner_offsets() {
+        let r = region(2, 3, 4, 5);
+        let (top_left, top_right, bottom_left, bottom_right) = r.corner_offsets();
+        assert_eq!(top_left, offset(2, 3));
+        assert_eq!(top_right, r.top_right());
+        assert_eq!(bottom_left, r.bottom_left());
+        assert_eq!(bottom_right, r.bottom_right());
+        assert_eq!(bottom_right, offset(6, 8));
+    }
+
+    #[test]
+    fn test_iter_lines() {
+        with_py(|py| {
+            let r = region(3, 5, 7, 4);
+            let lines = Bound::new(py, r.iter_lines()).unwrap();
+            let lines: Vec<(i32, i32, i32)> = lines
+                .try_iter()
+                .unwrap()
+                .map(|line| line.unwrap().extract().unwrap())
+                .collect();
+            let expected: Vec<(i32, i32, i32)> = (5..9).map(|y| (y, 3, 7)).collect();
+            assert_eq!(lines, expected);
+        });
+    }
+
+    #[test]
+    fn test_clip_line() {
+        let r = region(10, 5, 20, 3);
+        assert_eq!(r.clip_line(6, 0, 15), Some((10, 15)));
+        assert_eq!(r.clip_line(5, 25, 40), Some((25, 30)));
+        assert_eq!(r.clip_line(7, 12, 18), Some((12, 18)));
+        assert_eq!(r.clip_line(4, 12, 18), None);
+        assert_eq!(r.clip_line(8, 12, 18), None);
+        assert_eq!(r.clip_line(6, 0, 10), None);
+    }
+
+    #[test]
+    fn test_copy_constructors() {
+        with_py(|py| {
+            let copy = GeometryOffset::new(Some(&to_py(py, offset(1, 2))), None).unwrap();
+            assert_eq!(copy, offset(1, 2));
+            let copy = GeometryOffset::new(Some(&to_py(py, (1, 2))), None).unwrap();
+            assert_eq!(copy, offset(1, 2));
+
+            let copy = Size::new(Some(&to_py(py, size(3, 4))), None).unwrap();
+            assert_eq!(copy, size(3, 4));
+            let copy = Size::new(Some(&to_py(py, (3, 4))), None).unwrap();
+            assert_eq!(copy, size(3, 4));
+
+            let copy = Region::new(Some(&to_py(py, region(1, 2, 3, 4))), None, None, None);
+            assert_eq!(copy.unwrap(), region(1, 2, 3, 4));
+            let copy = Region::new(Some(&to_py(py, (1, 2, 3, 4))), None, None, None);
+            assert_eq!(copy.unwrap(), region(1, 2, 3, 4));
+
+            let copy = Spacing::new(Some(&to_py(py, spacing(1, 2, 3, 4))), None, None, None);
+            assert_eq!(copy.unwrap(), spacing(1, 2, 3, 4));
+            let copy = Spacing::new(Some(&to_py(py, (1, 2, 3, 4))), None, None, None);
+            assert_eq!(copy.unwrap(), spacing(1, 2, 3, 4));
+
+            // A tuple of the wrong length is rejected
+            assert!(Region::new(Some(&to_py(py, (1, 2))), None, None, None).is_err());
+            assert!(GeometryOffset::new(Some(&to_py(py, (1, 2, 3))), None).is_err());
+        });
+    }
+
+    #[test]
+    fn test_count_and_index() {
+        with_py(|py| {
+            let one = to_py(py, 1);
+            let ten = to_py(py, 10);
+            assert_eq!(spacing(1, 1, 2, 1).count(&one), 3);
+            assert_eq!(region(0, 0, 10, 5).count(&ten), 1);
+            assert_eq!(offset(1, 1).count(&to_py(py, "1")), 0);
+            assert_eq!(region(0, 0, 10, 5).index(&ten).unwrap(), 2);
+            assert_eq!(size(4, 1).index(&one).unwrap(), 1);
+            let error = offset(2, 3).index(&one).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_reflected_arithmetic() {
+        with_py(|py| {
+            let locals = PyDict::new(py);
+            locals.set_item("offset", offset(3, 4)).unwrap();
+            locals.set_item("size", size(3, 4)).unwrap();
+            locals.set_item("spacing", spacing(1, 2, 3, 4)).unwrap();
+            let eval =
+                |expression: &std::ffi::CStr| py.eval(expression, None, Some(&locals)).unwrap();
+
+            let result: GeometryOffset = eval(c"(1, 2) + offset").extract().unwrap();
+            assert_eq!(result, offset(4, 6));
+            let result: GeometryOffset = eval(c"(1, 2) - offset").extract().unwrap();
+            assert_eq!(result, offset(-2, -2));
+            let result: Size = eval(c"(1, 2) + size").extract().unwrap();
+            assert_eq!(result, size(4, 6));
+            let result: Size = eval(c"(5, 5) - size").extract().unwrap();
+            assert_eq!(result, size(2, 1));
+            let result: Spacing = eval(c"(1, 1, 1, 1) + spacing").extract().unwrap();
+            assert_eq!(result, spacing(2, 3, 4, 5));
+            let result: Spacing = eval(c"(5, 5, 5, 5) - spacing").extract().unwrap();
+            assert_eq!(result, spacing(4, 3, 2, 1));
+        });
+    }
+
+    #[test]
+    fn test_negative_dimensions() {
+        with_py(|py| {
+            let five = to_py(py, 5);
+            let r = Region::new(Some(&five), Some(5), Some(-3), Some(-2)).unwrap();
+            assert_eq!(r, region(2, 3, 3, 2));
+            assert_eq!((r.right(), r.bottom()), (5, 5));
+            let r = Region::new(Some(&to_py(py, 10)), Some(0), Some(-1), Some(5)).unwrap();
+            assert_eq!(r, region(9, 0, 1, 5));
+            let r = Region::new(Some(&to_py(py, (5, 5, -3, 2))), None, None, None).unwrap();
+            assert_eq!(r, region(2, 5, 3, 2));
+            let r = Region::new(None, Some(4), Some(3), Some(-4)).unwrap();
+            assert_eq!(r, region(0, 0, 3, 4));
+        });
+    }
+
+    #[test]
+    fn test_resize() {
+        with_py(|py| {
+            let r = region(3, 4, 10, 10);
+            assert_eq!(
+                r.resize(&to_py(py, 5), Some(6)).unwrap(),
+                region(3, 4, 5, 6)
+            );
+            assert_eq!(
+                r.resize(&to_py(py, size(7, 8)), None).unwrap(),
+                region(3, 4, 7, 8)
+            );
+            assert_eq!(
+                r.resize(&to_py(py, (1, 2)), None).unwrap(),
+                region(3, 4, 1, 2)
+            );
+            assert_eq!(
+                r.resize(&to_py(py, -5), Some(-1)).unwrap(),
+                region(3, 4, 0, 0)
+            );
+        });
+    }
+
+    #[test]
+    fn test_region_add_dispatch() {
+        with_py(|py| {
+            let r = region(1, 2, 3, 4);
+            assert_eq!(
+                r.__add__(&to_py(py, size(10, 20))).unwrap(),
+                region(1, 2, 13, 24)
+            );
+            assert_eq!(
+                r.__add__(&to_py(py, offset(10, 20))).unwrap(),
+                region(11, 22, 3, 4)
+            );
+            assert_eq!(
+                r.__add__(&to_py(py, (10, 20))).unwrap(),
+                region(11, 22, 3, 4)
+            );
+            let error = r.__add__(&to_py(py, "nope")).unwrap_err();
+            assert!(error.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_align_size() {
+        with_py(|py| {
+            let container = region(10, 20, 30, 12);
+            let child = to_py(py, size(10, 4));
+            let expected = [
+                ("left", "top", (10, 20)),
+                ("left", "middle", (10, 24)),
+                ("left", "bottom", (10, 28)),
+                ("center", "top", (20, 20)),
+                ("center", "middle", (20, 24)),
+                ("center", "bottom", (20, 28)),
+                ("right", "top", (30, 20)),
+                ("right", "middle", (30, 24)),
+                ("right", "bottom", (30, 28)),
+            ];
+            for (horizontal, vertical, (x, y)) in expected {
+                let aligned = container.align_size(&child, horizontal, vertical).unwrap();
+                assert_eq!(aligned, region(x, y, 10, 4), "{horizontal} {vertical}");
+            }
+            for (horizontal, vertical) in [("middle", "top"), ("left", "center")] {
+                let error = container
+                    .align_size(&child, horizontal, vertical)
+                    .unwrap_err();
+                assert!(error.is_instance_of::<PyValueError>(py));
+            }
+        });
+    }
+
+    #[test]
+    fn test_place_center() {
+        with_py(|py| {
+            let even = region(0, 0, 10, 8);
+            let odd = region(1, 1, 9, 7);
+            let place = |container: &Region, width: i32, height: i32| {
+                container
+                    .place_center(&to_py(py, size(width, height)))
+                    .unwrap()
+            };
+            assert_eq!(place(&even, 4, 2), region(3, 3, 4, 2));
+            assert_eq!(place(&even, 3, 3), region(3, 2, 3, 3));
+            assert_eq!(place(&odd, 3, 3), region(4, 3, 3, 3));
+            assert_eq!(place(&odd, 4, 2), region(3, 3, 4, 2));
+        });
+    }
+
+    #[test]
+    fn test_offset_within() {
+        let r = region(2, 3, 4, 5);
+        let cases = [
+            ((3, 4), true),
+            ((2, 3), true),
+            ((5, 7), true),
+            ((1, 4), false),
+            ((6, 4), false),
+            ((3, 2), false),
+            ((3, 8), false),
+        ];
+        for ((x, y), inside) in cases {
+            assert_eq!(offset(x, y).within(&r), inside, "{x}, {y}");
+            assert_eq!(offset(x, y).within(&r), r.contains(x, y));
+        }
+    }
+
+    #[test]
+    fn test_touches() {
+        let r = region(0, 0, 4, 4);
+        // Sharing an edge on each side
+        assert!(r.touches(&region(4, 0, 2, 4)));
+        assert!(r.touches(&region(-2, 1, 2, 2)));
+        assert!(r.touches(&region(1, 4, 2, 2)));
+        assert!(r.touches(&region(0, -3, 4, 3)));
+        // Corners only
+        assert!(!r.touches(&region(4, 4, 2, 2)));
+        assert!(!r.touches(&region(-2, -2, 2, 2)));
+        // Overlapping counts as touching
+        assert!(r.touches(&region(2, 2, 4, 4)));
+        // Disjoint or empty
+        assert!(!r.touches(&region(5, 0, 2, 2)));
+        assert!(!r.touches(&region(4, 0, 0, 4)));
+    }
+
+    #[test]
+    fn test_coalesce_regions() {
+        with_py(|py| {
+            let row: Vec<Region> = (0..5).map(|x| region(x * 2, 0, 2, 1)).collect();
+            let coalesced = coalesce_regions(&to_py(py, row)).unwrap();
+            assert_eq!(coalesced, vec![region(0, 0, 10, 1)]);
+
+            let disjoint = vec![region(0, 0, 2, 2), region(5, 5, 2, 2), region(0, 5, 1, 1)];
+            let coalesced = coalesce_regions(&to_py(py, disjoint.clone())).unwrap();
+            assert_eq!(coalesced, disjoint);
+
+            let overlapping = vec![region(0, 0, 4, 4), region(2, 2, 4, 4), region(20, 0, 1, 1)];
+            let coalesced = coalesce_regions(&to_py(py, overlapping)).unwrap();
+            assert_eq!(coalesced, vec![region(0, 0, 6, 6), region(20, 0, 1, 1)]);
+
+            let empty: Vec<Region> = Vec::new();
+            assert!(coalesce_regions(&to_py(py, empty)).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_snap_up() {
+        with_py(|py| {
+            assert_eq!(size(8, 6).snap_up(4, 3).unwrap(), size(8, 6));
+            assert_eq!(size(9, 7).snap_up(4, 3).unwrap(), size(12, 9));
+            assert_eq!(size(1, 1).snap_up(4, 3).unwrap(), size(4, 3));
+            assert_eq!(size(0, 0).snap_up(4, 3).unwrap(), size(0, 0));
+            let error = size(5, 5).snap_up(0, 3).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_mirror() {
+        assert_eq!(offset(0, 5).mirror_x(10), offset(9, 5));
+        assert_eq!(offset(3, 5).mirror_x(10), offset(6, 5));
+        assert_eq!(offset(0, 5).mirror_x(1), offset(0, 5));
+        assert_eq!(offset(5, 0).mirror_y(4), offset(5, 3));
+        for width in [1, 7, 80] {
+            let point = offset(4, 2);
+            assert_eq!(point.mirror_x(width).mirror_x(width), point);
+            assert_eq!(point.mirror_y(width).mirror_y(width), point);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let area = |regions: &[Region]| regions.iter().map(|r| r.area()).sum::<i32>();
+        let a = region(0, 0, 4, 4);
+        let b = region(2, 2, 4, 4);
+        let pieces = a.symmetric_difference(&b);
+        assert_eq!(area(&pieces), 16 + 16 - 2 * 4);
+        assert!(pieces
+            .iter()
+            .all(|piece| !piece.overlaps(&a.intersection(&b))));
+        for (index, piece) in pieces.iter().enumerate() {
+            assert!(pieces[index + 1..]
+                .iter()
+                .all(|other| !piece.overlaps(other)));
+        }
+
+        let disjoint = region(10, 10, 2, 2);
+        assert_eq!(a.symmetric_difference(&disjoint), vec![a, disjoint]);
+        assert!(a.symmetric_difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_tile() {
+        with_py(|py| {
+            let tiles = region(0, 0, 2, 3).tile(&region(10, 10, 4, 6)).unwrap();
+            assert_eq!(
+                tiles,
+                vec![
+                    region(10, 10, 2, 3),
+                    region(12, 10, 2, 3),
+                    region(10, 13, 2, 3),
+                    region(12, 13, 2, 3),
+                ]
+            );
+            let tiles = region(0, 0, 3, 2).tile(&region(0, 0, 5, 3)).unwrap();
+            assert_eq!(
+                tiles,
+                vec![
+                    region(0, 0, 3, 2),
+                    region(3, 0, 2, 2),
+                    region(0, 2, 3, 1),
+                    region(3, 2, 2, 1),
+                ]
+            );
+            let error = region(0, 0, 0, 2).tile(&region(0, 0, 5, 3)).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        with_py(|py| {
+            let r = region(1, 2, 3, 4);
+            let first = r.__hash__();
+            assert_eq!(r.__hash__(), first);
+            assert_eq!(region(1, 2, 3, 4).__hash__(), first);
+            assert_ne!(region(1, 2, 4, 3).__hash__(), first);
+            assert_eq!(to_py(py, r).hash().unwrap(), first);
+
+            let s = size(5, 6);
+            assert_eq!(s.__hash__(), size(5, 6).__hash__());
+
+            let p = spacing(1, 2, 3, 4);
+            assert_eq!(p.__hash__(), spacing(1, 2, 3, 4).__hash__());
+        });
+    }
+
+    #[test]
+    fn test_get_distance_squared_to() {
+        with_py(|py| {
+            let origin = offset(1, 1);
+            let points = [
+                offset(4, 5),
+                offset(-3, 1),
+                offset(1, 1),
+                offset(10, -2),
+                offset(2, 3),
+            ];
+            for a in &points {
+                let squared = origin
+                    .get_distance_squared_to(&to_py(py, a.clone()))
+                    .unwrap();
+                let distance = origin.get_distance_to(a.clone());
+                assert!((squared as f64 - distance * distance).abs() < 1e-9);
+                for b in &points {
+                    let other = origin
+                        .get_distance_squared_to(&to_py(py, (b.x, b.y)))
+                        .unwrap();
+                    assert_eq!(
+                        squared.cmp(&other),
+                        origin
+                            .get_distance_to(a.clone())
+                            .total_cmp(&origin.get_distance_to(b.clone()))
+                    );
+                }
+            }
+            let far = offset(i32::MIN, i32::MIN);
+            let squared = far.get_distance_squared_to(&to_py(py, (i32::MAX, i32::MAX)));
+            assert_eq!(squared.unwrap(), 2 * (u32::MAX as i128).pow(2));
+        });
+    }
+
+    #[test]
+    fn test_distances() {
+        with_py(|py| {
+            let origin = offset(2, -1);
+            let points = vec![(2, -1), (5, 3), (-4, 7), (0, 0)];
+            let result = distances(py, &to_py(py, origin.clone()), &to_py(py, points.clone()));
+            let squared =
+                distances_squared(py, &to_py(py, (2, -1)), &to_py(py, points.clone())).unwrap();
+            for ((distance, squared), (x, y)) in
+                result.unwrap().into_iter().zip(squared).zip(points)
+            {
+                assert_eq!(distance, origin.get_distance_to(offset(x, y)));
+                let dx = (x - origin.x) as i128;
+                let dy = (y - origin.y) as i128;
+                assert_eq!(squared, dx * dx + dy * dy);
+            }
+            let empty: Vec<(i32, i32)> = Vec::new();
+            assert!(distances(py, &to_py(py, (0, 0)), &to_py(py, empty))
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[test]
+    fn test_spacing_grow_and_shrink() {
+        with_py(|py| {
+            let margin = spacing(1, 2, 3, 4);
+            let r = region(10, 10, 20, 20);
+            let margin_object = to_py(py, margin);
+            assert_eq!(margin.grow(&r), r.grow(&margin_object).unwrap());
+            assert_eq!(margin.shrink(&r), r.shrink(&margin_object, None).unwrap());
+            assert_eq!(margin.grow(&r), region(6, 9, 26, 24));
+            assert_eq!(margin.shrink(&r), region(14, 11, 14, 16));
+        });
+    }
+
+    #[test]
+    fn test_pad_size() {
+        let padding = spacing(1, 2, 3, 4);
+        assert_eq!(padding.pad_size(), size(padding.width(), padding.height()));
+        assert_eq!(padding.pad_size(), size(6, 4));
+    }
+
+    #[test]
+    fn test_offset_clamp_non_positive_size() {
+        assert_eq!(offset(5, 5).clamp(0, 0, false), offset(0, 0));
+        assert_eq!(offset(5, 5).clamp(-3, -3, false), offset(0, 0));
+        assert_eq!(offset(-5, -5).clamp(0, 0, false), offset(0, 0));
+        assert_eq!(offset(5, 5).clamp(1, 1, false), offset(0, 0));
+        assert_eq!(offset(5, -5).clamp(10, 0, false), offset(5, 0));
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(clamp(5, 0, 10), 5);
+        assert_eq!(clamp(-1, 0, 10), 0);
+        assert_eq!(clamp(11, 0, 10), 10);
+        assert_eq!(clamp(3, 3, 3), 3);
+    }
+
+    #[test]
+    fn test_clamp_inverted_bounds() {
+        // Swapped bounds behave as if they were given in order
+        assert_eq!(clamp(-1, 10, 0), 0);
+        assert_eq!(clamp(0, 10, 0), 0);
+        assert_eq!(clamp(5, 10, 0), 5);
+        assert_eq!(clamp(10, 10, 0), 10);
+        assert_eq!(clamp(11, 10, 0), 10);
+        for value in -5..15 {
+            assert_eq!(clamp(value, 10, 0), clamp(value, 0, 10));
+        }
+    }
+
+    #[test]
+    fn test_inside() {
+        let container = region(0, 0, 10, 10);
+        assert!(region(2, 2, 3, 3).inside(&container));
+        assert!(region(0, 0, 10, 10).inside(&container));
+        assert!(!region(8, 8, 3, 3).inside(&container));
+        assert!(!region(-1, 0, 3, 3).inside(&container));
+    }
+
+    #[test]
+    fn test_clamp_to() {
+        let container = region(0, 0, 10, 10);
+        assert_eq!(
+            region(5, 5, 10, 10).clamp_to(&container),
+            region(5, 5, 5, 5)
+        );
+        assert_eq!(region(-3, 2, 5, 4).clamp_to(&container), region(0, 2, 2, 4));
+        assert_eq!(region(2, 2, 3, 3).clamp_to(&container), region(2, 2, 3, 3));
+        let disjoint = region(20, 20, 5, 5).clamp_to(&container);
+        assert_eq!(disjoint.area(), 0);
+        assert!(disjoint.width >= 0 && disjoint.height >= 0);
+    }
+
+    #[test]
+    fn test_round_to_int() {
+        assert_eq!(round_to_int(2.5, "floor").unwrap(), 2);
+        assert_eq!(round_to_int(2.5, "round").unwrap(), 2);
+        assert_eq!(round_to_int(3.5, "round").unwrap(), 4);
+        assert_eq!(round_to_int(2.1, "ceil").unwrap(), 3);
+        assert_eq!(round_to_int(-2.5, "floor").unwrap(), -3);
+        assert!(round_to_int(1.0, "up").is_err());
+    }
+
+    #[test]
+    fn test_region_scale() {
+        with_py(|py| {
+            let r = region(3, 3, 5, 7);
+            assert_eq!(r.scale(0.5, 0.5, "floor").unwrap(), region(1, 1, 2, 3));
+            assert_eq!(r.scale(0.5, 0.5, "round").unwrap(), region(2, 2, 2, 4));
+            assert_eq!(r.scale(0.5, 0.5, "ceil").unwrap(), region(2, 2, 3, 4));
+            assert_eq!(r.scale(2.0, 1.0, "floor").unwrap(), region(6, 3, 10, 7));
+            let error = r.scale(0.5, 0.5, "nearest").unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_offset_sign() {
+        assert_eq!(offset(3, 4).sign(), offset(1, 1));
+        assert_eq!(offset(-3, 4).sign(), offset(-1, 1));
+        assert_eq!(offset(-3, -4).sign(), offset(-1, -1));
+        assert_eq!(offset(3, -4).sign(), offset(1, -1));
+        assert_eq!(offset(0, -4).sign(), offset(0, -1));
+        assert_eq!(offset(0, 0).sign(), offset(0, 0));
+        assert_eq!(offset(3, 4).magnitude(), 5.0);
+        assert_eq!(offset(0, 0).magnitude(), 0.0);
+    }
+
+    #[test]
+    fn test_divide_span() {
+        assert_eq!(divide_span(0, 10, 3, 0), vec![(0, 4), (4, 3), (7, 3)]);
+        assert_eq!(divide_span(5, 10, 3, 1), vec![(5, 3), (9, 3), (13, 2)]);
+        // Gutters too wide for the span are narrowed so nothing runs past the end
+        assert_eq!(divide_span(0, 2, 3, 2), vec![(0, 0), (1, 0), (2, 0)]);
+        assert_eq!(divide_span(0, 5, 3, 4), vec![(0, 1), (3, 0), (5, 0)]);
+        assert_eq!(divide_span(3, 0, 2, 1), vec![(3, 0), (3, 0)]);
+        assert_eq!(divide_span(3, -4, 2, 1), vec![(3, 0), (3, 0)]);
+        assert_eq!(divide_span(0, 3, 1, 10), vec![(0, 3)]);
+        for (length, count, gutter) in [(2, 3, 2), (5, 3, 4), (7, 4, 3), (10, 2, 20), (9, 5, 1)] {
+            let spans = divide_span(0, length, count, gutter);
+            let (last_start, last_size) = spans[spans.len() - 1];
+            assert_eq!(last_start + last_size, length, "{length} {count} {gutter}");
+        }
+    }
+
+    #[test]
+    fn test_divide() {
+        with_py(|py| {
+            let r = region(2, 3, 23, 11);
+            let cells = r.divide(4, 3, Some(&to_py(py, (1, 2)))).unwrap();
+            assert_eq!(cells.len(), 12);
+            // Within each row the cells and gutters cover the full width, and likewise for columns
+            for row in cells.chunks(4) {
+                assert_eq!(row[0].x, r.x);
+                assert_eq!(row[3].right(), r.right());
+                for pair in row.windows(2) {
+                    assert_eq!(pair[1].x - pair[0].right(), 1);
+                }
+            }
+            for column in 0..4 {
+                let column: Vec<&Region> = cells.iter().skip(column).step_by(4).collect();
+                assert_eq!(column[0].y, r.y);
+                assert_eq!(column[2].bottom(), r.bottom());
+                for pair in column.windows(2) {
+                    assert_eq!(pair[1].y - pair[0].bottom(), 2);
+                }
+            }
+            let cells = r.divide(2, 1, Some(&to_py(py, 3))).unwrap();
+            assert_eq!(cells, vec![region(2, 3, 10, 11), region(15, 3, 10, 11)]);
+            // A gutter wider than the region is narrowed, and the cells still tile it
+            let cells = region(0, 0, 4, 2)
+                .divide(3, 1, Some(&to_py(py, 5)))
+                .unwrap();
+            assert_eq!(
+                cells,
+                vec![region(0, 0, 0, 2), region(2, 0, 0, 2), region(4, 0, 0, 2)]
+            );
+            assert!(r.divide(0, 1, None).is_err());
+            assert!(r.divide(1, 1, Some(&to_py(py, -1))).is_err());
+        });
+    }
+
+    #[test]
+    fn test_wrap_offset() {
+        with_py(|py| {
+            let r = region(10, 20, 5, 4);
+            let wrap = |x: i32, y: i32| r.wrap_offset(&to_py(py, (x, y))).unwrap();
+            assert_eq!(wrap(12, 21), offset(12, 21));
+            assert_eq!(wrap(9, 19), offset(14, 23));
+            assert_eq!(wrap(0, 0), offset(10, 20));
+            assert_eq!(wrap(15, 24), offset(10, 20));
+            assert_eq!(wrap(1012, 2021), offset(12, 21));
+            assert_eq!(wrap(-1003, -999), offset(12, 21));
+            let empty = region(0, 0, 0, 4);
+            let error = empty.wrap_offset(&to_py(py, (1, 1))).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_at_offset_and_at_origin() {
+        with_py(|py| {
+            let r = region(5, 6, 7, 8);
+            assert_eq!(
+                r.at_offset(&to_py(py, offset(3, 4))).unwrap(),
+                region(3, 4, 7, 8)
+            );
+            assert_eq!(r.at_offset(&to_py(py, (3, 4))).unwrap(), region(3, 4, 7, 8));
+            assert_eq!(r.at_origin(), region(0, 0, 7, 8));
+            assert_eq!(r.at_origin(), r.reset_offset());
+        });
+    }
+
+    #[test]
+    fn test_split_clamps_cuts() {
+        let r = region(10, 20, 8, 6);
+        let non_negative =
+            |pieces: &[&Region]| pieces.iter().all(|p| p.width >= 0 && p.height >= 0);
+
+        let (a, b, c, d) = r.split(-100, -100);
+        assert!(non_negative(&[&a, &b, &c, &d]));
+        assert_eq!(d, region(10, 20, 8, 6));
+        let (a, b, c, d) = r.split(0, 0);
+        assert_eq!((a.area(), b.area(), c.area()), (0, 0, 0));
+        assert_eq!(d, r);
+        let (a, b, c, d) = r.split(100, 100);
+        assert!(non_negative(&[&a, &b, &c, &d]));
+        assert_eq!(a, r);
+        let (a, b, c, d) = r.split(-3, 2);
+        assert_eq!(
+            (a, b, c, d),
+            (
+                region(10, 20, 5, 2),
+                region(15, 20, 3, 2),
+                region(10, 22, 5, 4),
+                region(15, 22, 3, 4)
+            )
+        );
+
+        assert_eq!(r.split_horizontal(-100), (region(10, 20, 8, 0), r));
+        assert_eq!(r.split_horizontal(0), (region(10, 20, 8, 0), r));
+        assert_eq!(r.split_horizontal(100), (r, region(10, 26, 8, 0)));
+        assert_eq!(r.split_vertical(-100), (region(10, 20, 0, 6), r));
+        assert_eq!(r.split_vertical(0), (region(10, 20, 0, 6), r));
+        assert_eq!(r.split_vertical(100), (r, region(18, 20, 0, 6)));
+    }
+
+    #[test]
+    fn test_get_margin_to() {
+        let outer = region(0, 0, 10, 10);
+        let poking = region(-2, 3, 5, 9);
+        assert_eq!(outer.get_margin_to(&poking, false), spacing(3, 7, -2, -2));
+        assert_eq!(outer.get_margin_to(&poking, true), spacing(3, 7, 0, 0));
+        assert_eq!(
+            outer.get_margin_to(&poking, false),
+            outer.get_spacing_between(&poking)
+        );
+        let inside = region(1, 2, 3, 4);
+        assert_eq!(
+            outer.get_margin_to(&inside, true),
+            outer.get_margin_to(&inside, false)
+        );
+    }
+
+    #[test]
+    fn test_inflect() {
+        let r = region(10, 10, 4, 2);
+        assert_eq!(r.inflect(1, 1, None), region(14, 12, 4, 2));
+        assert_eq!(r.inflect(-1, -1, None), region(6, 8, 4, 2));
+        assert_eq!(r.inflect(0, 1, None), region(10, 12, 4, 2));
+        assert_eq!(r.inflect(1, 0, None), region(14, 10, 4, 2));
+
+        // The larger edge on each axis is used: right (5) and bottom (3)
+        let margin = spacing(1, 5, 3, 2);
+        assert_eq!(r.inflect(1, 1, Some(margin)), region(19, 15, 4, 2));
+        assert_eq!(r.inflect(-1, 0, Some(margin)), region(1, 10, 4, 2));
+        assert_eq!(r.inflect(0, -1, Some(margin)), region(10, 5, 4, 2));
+    }
+
+    #[test]
+    fn test_cell_count() {
+        assert_eq!(region(0, 0, 3, 4).cell_count(), 12);
+        assert_eq!(region(0, 0, 100_000, 100_000).cell_count(), 10_000_000_000);
+        assert_eq!(
+            region(0, 0, i32::MAX, i32::MAX).cell_count(),
+            i32::MAX as i64 * i32::MAX as i64
+        );
+    }
+
+    #[test]
+    fn test_offset_size_conversions() {
+        assert_eq!(offset(3, 4).to_size(), size(3, 4));
+        // Negative components are kept
+        assert_eq!(offset(-3, 4).to_size(), size(-3, 4));
+        assert_eq!(size(3, 4).to_offset(), offset(3, 4));
+        assert_eq!(size(-3, -4).to_offset(), offset(-3, -4));
+        assert_eq!(offset(-1, 2).to_size().to_offset(), offset(-1, 2));
+    }
+
+    #[test]
+    fn test_spacing_as_region() {
+        with_py(|py| {
+            let padding = spacing(1, 2, 3, 4);
+            let outer = padding.as_region(&to_py(py, size(10, 5))).unwrap();
+            let grown = size(10, 5).region().grow(&to_py(py, padding)).unwrap();
+            assert_eq!(outer, grown);
+            assert_eq!(outer, region(-4, -1, 16, 9));
+            assert_eq!(padding.as_region(&to_py(py, (10, 5))).unwrap(), outer);
+        });
+    }
+
+    #[test]
+    fn test_center_region() {
+        with_py(|py| {
+            let container = region(10, 10, 20, 10);
+            let center = |width: i32, height: i32| {
+                container
+                    .center_region(&to_py(py, (width, height)))
+                    .unwrap()
+            };
+            assert_eq!(center(4, 2), region(18, 14, 4, 2));
+            assert_eq!(center(20, 10), container);
+            // Oversized children align to the top left rather than overflowing
+            assert_eq!(center(30, 16), region(10, 10, 30, 16));
+        });
+    }
+
+    #[test]
+    fn test_offset_in_frozenset() {
+        with_py(|py| {
+            let items = [offset(1, 2), offset(1, 2), offset(2, 1)];
+            let set = pyo3::types::PyFrozenSet::new(py, items).unwrap();
+            assert_eq!(set.len(), 2);
+            assert!(set.contains(offset(1, 2)).unwrap());
+            assert!(set.contains(offset(2, 1)).unwrap());
+            assert!(!set.contains(offset(3, 3)).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_get_scroll_to_visible() {
+        with_py(|py| {
+            let cls = py.get_type::<Region>();
+            let window = region(0, 0, 10, 10);
+            let scroll = |target: Region, top: bool, overscroll: i32| {
+                Region::get_scroll_to_visible(&cls, &window, &target, top, overscroll)
+            };
+            assert_eq!(scroll(region(2, 2, 3, 3), false, 0), offset(0, 0));
+            assert_eq!(scroll(region(2, 12, 3, 3), false, 0), offset(0, 5));
+            assert_eq!(scroll(region(-4, 2, 3, 3), false, 0), offset(-4, 0));
+            assert_eq!(scroll(region(2, 4, 3, 3), true, 0), offset(0, 4));
+            // Overscroll is applied when the region is already flush with an edge
+            assert_eq!(scroll(region(4, 8, 2, 2), false, 2), offset(0, 2));
+            assert_eq!(scroll(region(0, 8, 2, 2), false, 2), offset(-2, 2));
+            assert_eq!(scroll(region(2, 12, 3, 3), false, 1), offset(0, 6));
+            // and when scrolling the region to the top
+            assert_eq!(scroll(region(2, 4, 3, 3), true, 1), offset(0, 3));
+            // Overscroll is limited so the region stays inside the window
+            assert_eq!(scroll(region(4, 12, 3, 8), false, 5), offset(0, 11));
+        });
+    }
+
+    #[test]
+    fn test_crop_size() {
+        with_py(|py| {
+            let base = region(1, 2, 10, 8);
+            assert_eq!(
+                base.crop_size(&to_py(py, size(4, 3))).unwrap(),
+                region(1, 2, 4, 3)
+            );
+            assert_eq!(
+                base.crop_size(&to_py(py, (20, 5))).unwrap(),
+                region(1, 2, 10, 5)
+            );
+            // Negative dimensions crop to nothing
+            assert_eq!(
+                base.crop_size(&to_py(py, (-3, 4))).unwrap(),
+                region(1, 2, 0, 4)
+            );
+        });
+    }
+
+    #[test]
+    fn test_clip() {
+        with_py(|py| {
+            let err = region(0, 0, 5, 5).clip(-1, 5).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            let err = region(0, 0, 5, 5).clip(5, -1).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+        assert_eq!(region(2, 2, 10, 10).clip(8, 6).unwrap(), region(2, 2, 6, 4));
+        assert_eq!(region(-2, -2, 5, 5).clip(8, 6).unwrap(), region(0, 0, 3, 3));
+        assert_eq!(region(10, 10, 5, 5).clip(8, 6).unwrap(), region(8, 6, 0, 0));
+        assert_eq!(region(1, 1, 2, 2).clip(0, 0).unwrap(), region(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_gap_to() {
+        let origin = region(0, 0, 4, 4);
+        assert_eq!(origin.gap_to(&region(7, 0, 2, 2)), 3);
+        assert_eq!(origin.gap_to(&region(0, 6, 2, 2)), 2);
+        assert_eq!(region(7, 0, 2, 2).gap_to(&origin), 3);
+        // Diagonal gaps take the larger axis
+        assert_eq!(origin.gap_to(&region(6, 9, 2, 2)), 5);
+        // Touching and overlapping regions have no gap
+        assert_eq!(origin.gap_to(&region(4, 0, 2, 2)), 0);
+        assert_eq!(origin.gap_to(&region(2, 2, 4, 4)), 0);
+    }
+
+    #[test]
+    fn test_lerp_and_blend() {
+        let start = offset(0, 0);
+        let end = offset(10, 20);
+        assert_eq!(start.lerp(end.clone(), 0.5), start.blend(end.clone(), 0.5));
+        // blend extrapolates outside of 0..1, lerp clamps
+        assert_eq!(start.blend(end.clone(), -0.5), offset(-5, -10));
+        assert_eq!(start.lerp(end.clone(), -0.5), offset(0, 0));
+        assert_eq!(start.blend(end.clone(), 1.5), offset(15, 30));
+        assert_eq!(start.lerp(end.clone(), 1.5), offset(10, 20));
+    }
+
+    #[test]
+    fn test_contains_region_strict() {
+        let outer = region(0, 0, 10, 10);
+        assert!(outer.contains_region(&region(2, 2, 4, 4), false));
+        assert!(outer.contains_region(&region(2, 2, 4, 4), true));
+        // Touching the edge is contained, but not strictly
+        assert!(outer.contains_region(&region(0, 0, 10, 10), false));
+        assert!(!outer.contains_region(&region(0, 0, 10, 10), true));
+        assert!(outer.contains_region(&region(0, 3, 2, 2), false));
+        assert!(!outer.contains_region(&region(0, 3, 2, 2), true));
+        assert!(!outer.contains_region(&region(8, 8, 4, 4), false));
+        assert!(!outer.contains_region(&region(8, 8, 4, 4), true));
+    }
+
+    #[test]
+    fn test_from_points() {
+        with_py(|py| {
+            let cls = py.get_type::<Region>();
+            let from_points =
+                |points: Vec<(i32, i32)>| Region::from_points(&cls, &to_py(py, points));
+            assert_eq!(from_points(vec![(3, 4)]).unwrap(), region(3, 4, 1, 1));
+            assert_eq!(
+                from_points(vec![(1, 2), (5, 2)]).unwrap(),
+                region(1, 2, 5, 1)
+            );
+            assert_eq!(
+                from_points(vec![(4, 4), (1, 1), (4, 1), (1, 4)]).unwrap(),
+                region(1, 1, 4, 4)
+            );
+            let err = from_points(vec![]).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_from_offset() {
+        with_py(|py| {
+            let cls = py.get_type::<Region>();
+            let expected = region(1, 2, 3, 4);
+            let from_offset = |offset: Bound<'_, PyAny>, size: Bound<'_, PyAny>| {
+                Region::from_offset(&cls, &offset, &size).unwrap()
+            };
+            assert_eq!(
+                from_offset(to_py(py, offset(1, 2)), to_py(py, size(3, 4))),
+                expected
+            );
+            assert_eq!(from_offset(to_py(py, (1, 2)), to_py(py, (3, 4))), expected);
+            assert_eq!(
+                from_offset(to_py(py, offset(1, 2)), to_py(py, (3, 4))),
+                expected
+            );
+            assert_eq!(
+                from_offset(to_py(py, (1, 2)), to_py(py, size(3, 4))),
+                expected
+            );
+        });
+    }
+
+    #[test]
+    fn test_align_to() {
+        with_py(|py| {
+            let container = region(10, 10, 20, 20);
+            let child = region(15, 15, 4, 3);
+            assert_eq!(
+                child.align_to(&container, "top").unwrap(),
+                region(15, 10, 4, 3)
+            );
+            assert_eq!(
+                child.align_to(&container, "bottom").unwrap(),
+                region(15, 27, 4, 3)
+            );
+            assert_eq!(
+                child.align_to(&container, "left").unwrap(),
+                region(10, 15, 4, 3)
+            );
+            assert_eq!(
+                child.align_to(&container, "right").unwrap(),
+                region(26, 15, 4, 3)
+            );
+            let err = child.align_to(&container, "middle").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_size_region_at() {
+        with_py(|py| {
+            let cell = size(3, 2);
+            assert_eq!(
+                cell.region_at(&to_py(py, (5, 7))).unwrap(),
+                region(5, 7, 3, 2)
+            );
+            assert_eq!(
+                cell.region_at(&to_py(py, offset(-1, 4))).unwrap(),
+                region(-1, 4, 3, 2)
+            );
+        });
+    }
+
+    #[test]
+    fn test_region_transpose() {
+        let original = region(1, 2, 3, 4);
+        assert_eq!(original.transpose(), region(2, 1, 4, 3));
+        assert_eq!(original.transpose().transpose(), original);
+    }
+
+    #[test]
+    fn test_offset_transposed() {
+        assert_eq!(offset(1, 2).transposed(), offset(2, 1));
+        assert_eq!(offset(1, 2).transposed().transposed(), offset(1, 2));
+        // The tuple form is kept for compatibility
+        assert_eq!(offset(1, 2).transpose(), (2, 1));
+    }
+
+    #[test]
+    fn test_union_size() {
+        let cases = [
+            (region(0, 0, 2, 2), region(4, 5, 2, 2)),
+            (region(3, 3, 2, 2), region(0, 0, 10, 10)),
+            (region(-5, 2, 3, 1), region(1, -4, 2, 2)),
+        ];
+        for (a, b) in cases {
+            assert_eq!(a.union_size(&b), a.union(&b).size());
+        }
+        assert_eq!(
+            region(0, 0, 2, 2).union_size(&region(4, 5, 2, 2)),
+            size(6, 7)
+        );
+    }
+
+    #[test]
+    fn test_inset() {
+        let base = region(0, 0, 10, 7);
+        assert_eq!(base.inset(0.0), base);
+        assert_eq!(base.inset(0.5), region(5, 3, 0, 1));
+        assert_eq!(base.inset(0.2), region(2, 1, 6, 5));
+        // Out of range fractions are clamped
+        assert_eq!(base.inset(-1.0), base);
+        assert_eq!(base.inset(2.0), base.inset(0.5));
+    }
+
+    #[test]
+    fn test_offset_min_max() {
+        with_py(|py| {
+            let point = offset(3, -2);
+            assert_eq!(point.min(&to_py(py, offset(1, 5))).unwrap(), offset(1, -2));
+            assert_eq!(point.max(&to_py(py, offset(1, 5))).unwrap(), offset(3, 5));
+            assert_eq!(point.min(&to_py(py, (4, -4))).unwrap(), offset(3, -4));
+            assert_eq!(point.max(&to_py(py, (4, -4))).unwrap(), offset(4, -2));
+        });
+    }
+
+    #[test]
+    fn test_size_min_max() {
+        with_py(|py| {
+            let base = size(10, 4);
+            assert_eq!(base.min(&to_py(py, size(6, 8))).unwrap(), size(6, 4));
+            assert_eq!(base.max(&to_py(py, size(6, 8))).unwrap(), size(10, 8));
+            assert_eq!(base.min(&to_py(py, (12, 2))).unwrap(), size(10, 2));
+            assert_eq!(base.max(&to_py(py, (12, 2))).unwrap(), size(12, 4));
+        });
+    }
+
+    #[test]
+    fn test_summarize_regions() {
+        with_py(|py| {
+            let summarize = |regions: Vec<Region>| summarize_regions(&to_py(py, regions)).unwrap();
+            assert_eq!(summarize(vec![]), (None, 0));
+            assert_eq!(
+                summarize(vec![region(0, 0, 2, 2), region(5, 5, 3, 1)]),
+                (Some(region(0, 0, 8, 6)), 7)
+            );
+            // Empty regions don't contribute to the bounds or the area
+            assert_eq!(
+                summarize(vec![
+                    region(-10, -10, 0, 0),
+                    region(1, 1, 2, 2),
+                    region(20, 1, 5, 0)
+                ]),
+                (Some(region(1, 1, 2, 2)), 4)
+            );
+            assert_eq!(summarize(vec![region(3, 3, 0, 4)]), (None, 0));
+        });
+    }
+
+    #[test]
+    fn test_region_clamp_offset() {
+        with_py(|py| {
+            let area = region(2, 3, 4, 5);
+            let clamp = |point: (i32, i32)| area.clamp_offset(&to_py(py, point)).unwrap();
+            assert_eq!(clamp((3, 4)), offset(3, 4));
+            assert_eq!(clamp((0, 0)), offset(2, 3));
+            assert_eq!(clamp((10, 10)), offset(5, 7));
+            assert_eq!(clamp((4, 20)), offset(4, 7));
+            // Empty regions clamp to their origin
+            assert_eq!(
+                region(2, 3, 0, 0).clamp_offset(&to_py(py, (9, 9))).unwrap(),
+                offset(2, 3)
+            );
+        });
+    }
+
+    #[test]
+    fn test_offset_scale_rounding() {
+        with_py(|py| {
+            let point = offset(3, 3);
+            let half = to_py(py, 0.5);
+            assert_eq!(point.__mul__(&half).unwrap(), offset(1, 1));
+            assert_eq!(point.scale(&half, "floor").unwrap(), offset(1, 1));
+            assert_eq!(point.scale(&half, "round").unwrap(), offset(2, 2));
+            assert_eq!(point.scale(&half, "ceil").unwrap(), offset(2, 2));
+            assert_eq!(
+                point.scale(&to_py(py, (0.5, 2.0)), "round").unwrap(),
+                offset(2, 6)
+            );
+            let err = point.scale(&half, "nearest").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        with_py(|py| {
+            let content = region(10, 10, 8, 6);
+            let (outer, inner) = content.expand_collapse(&to_py(py, (1, 2, 3, 4))).unwrap();
+            assert_eq!(outer, region(6, 9, 14, 10));
+            assert_eq!(inner, region(14, 11, 2, 2));
+            assert_eq!(outer, content.grow(&to_py(py, (1, 2, 3, 4))).unwrap());
+            let (outer, inner) = content.expand_collapse(&to_py(py, (0, 0, 0, 0))).unwrap();
+            assert_eq!((outer, inner), (content, content));
+        });
+    }
+
+    #[test]
+    fn test_spacing_totals_variants() {
+        let padding = spacing(1, 2, 3, 4);
+        assert_eq!(padding.totals(), (6, 4));
+        assert_eq!(padding.totals_size(), size(6, 4));
+        assert_eq!(padding.top_left_offset(), offset(4, 1));
+    }
+
+    #[test]
+    fn test_clip_to() {
+        let clip = region(5, 5, 10, 10);
+        assert_eq!(region(0, 0, 8, 8).clip_to(&clip), region(5, 5, 3, 3));
+        assert_eq!(region(7, 7, 2, 2).clip_to(&clip), region(7, 7, 2, 2));
+        assert_eq!(region(12, 0, 10, 20).clip_to(&clip), region(12, 5, 3, 10));
+        let clipped = region(0, 0, 3, 3).clip_to(&clip);
+        assert_eq!((clipped.width, clipped.height), (0, 0));
+    }
+
+    #[test]
+    fn test_offset_add_offset_like() {
+        with_py(|py| {
+            let locals = PyDict::new(py);
+            locals
+                .set_item("Offset", py.get_type::<GeometryOffset>())
+                .unwrap();
+            locals.set_item("Region", py.get_type::<Region>()).unwrap();
+            py.run(
+                c"class Point:\n    def __init__(self, x, y):\n        self.x = x\n        self.y = y\n",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let sum: GeometryOffset = py
+                .eval(c"Offset(1, 1) + Point(2, 3)", None, Some(&locals))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(sum, offset(3, 4));
+            let difference: GeometryOffset = py
+                .eval(c"Offset(1, 1) - Point(2, 3)", None, Some(&locals))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(difference, offset(-1, -2));
+            // A Region has x and y, but isn't an offset
+            let err = py
+                .eval(c"Offset(1, 1) + Region(2, 3, 4, 5)", None, Some(&locals))
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_move_to() {
+        with_py(|py| {
+            let base = region(1, 2, 3, 4);
+            assert_eq!(
+                base.move_to(&to_py(py, (5, 6))).unwrap(),
+                region(5, 6, 3, 4)
+            );
+            assert_eq!(
+                base.move_to(&to_py(py, offset(-1, 0))).unwrap(),
+                region(-1, 0, 3, 4)
+            );
+            assert!(base.move_to(&to_py(py, "origin")).is_err());
+        });
+    }
+
+    #[test]
+    fn test_shrink_minimum() {
+        with_py(|py| {
+            let base = region(0, 0, 10, 6);
+            let margin = to_py(py, (2, 3, 2, 3));
+            assert_eq!(base.shrink(&margin, None).unwrap(), region(3, 2, 4, 2));
+            assert_eq!(
+                base.shrink(&margin, Some(&to_py(py, (3, 3)))).unwrap(),
+                region(3, 2, 4, 3)
+            );
+            assert_eq!(
+                base.shrink(&margin, Some(&to_py(py, size(1, 1)))).unwrap(),
+                region(3, 2, 4, 2)
+            );
+            // The minimum applies to over-shrunk regions too
+            let margin = to_py(py, (4, 6, 4, 6));
+            assert_eq!(
+                base.shrink(&margin, Some(&to_py(py, (2, 1)))).unwrap(),
+                region(6, 4, 2, 1)
+            );
+        });
+    }
+
+    #[test]
+    fn test_iter_grid() {
+        with_py(|py| {
+            let locals = PyDict::new(py);
+            locals.set_item("Region", py.get_type::<Region>()).unwrap();
+            let grid: Vec<(usize, usize, Region)> = py
+                .eval(
+                    c"list(Region(0, 0, 9, 4).iter_grid(3, 2))",
+                    None,
+                    Some(&locals),
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            let cells = region(0, 0, 9, 4).divide(3, 2, None).unwrap();
+            assert_eq!(grid.len(), 6);
+            for (index, (row, column, cell)) in grid.into_iter().enumerate() {
+                assert_eq!((row, column), (index / 3, index % 3));
+                assert_eq!(cell, cells[index]);
+            }
+            assert_eq!(cells[1], region(3, 0, 3, 2));
+            assert_eq!(cells[3], region(0, 2, 3, 2));
+        });
+    }
+
+    #[test]
+    fn test_clamp_between() {
+        with_py(|py| {
+            let minimum = to_py(py, (0, 0));
+            let maximum = to_py(py, offset(10, 5));
+            let clamp = |point: GeometryOffset| point.clamp_between(&minimum, &maximum).unwrap();
+            assert_eq!(clamp(offset(3, 4)), offset(3, 4));
+            assert_eq!(clamp(offset(-3, 8)), offset(0, 5));
+            assert_eq!(clamp(offset(12, -1)), offset(10, 0));
+            // Inverted bounds favour the minimum
+            assert_eq!(
+                offset(3, 3)
+                    .clamp_between(&to_py(py, (5, 5)), &to_py(py, (1, 1)))
+                    .unwrap(),
+                offset(5, 5)
+            );
+        });
+    }
+
+    #[test]
+    fn test_fits_in() {
+        with_py(|py| {
+            let content = size(4, 3);
+            assert!(content.fits_in(&to_py(py, size(4, 3))).unwrap());
+            assert!(content.fits_in(&to_py(py, (10, 10))).unwrap());
+            assert!(!content.fits_in(&to_py(py, (3, 10))).unwrap());
+            assert!(!content.fits_in(&to_py(py, (10, 2))).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_overlaps_properties() {
+        // A small LCG keeps the test deterministic without pulling in a rand dependency
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |low: i32, high: i32| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            low + ((state >> 33) % (high - low + 1) as u64) as i32
+        };
+        for _ in 0..10_000 {
+            let a = region(next(-10, 10), next(-10, 10), next(-2, 8), next(-2, 8));
+            let b = region(next(-10, 10), next(-10, 10), next(-2, 8), next(-2, 8));
+            assert_eq!(a.overlaps(&b), b.overlaps(&a), "{a:?} {b:?}");
+            let shared = a.intersection(&b);
+            let has_area = a.width > 0
+                && a.height > 0
+                && b.width > 0
+                && b.height > 0
+                && shared.width > 0
+                && shared.height > 0;
+            assert_eq!(a.overlaps(&b), has_area, "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_best_side() {
+        let container = region(0, 0, 40, 20);
+        let popup = region(0, 0, 10, 4);
+        assert_eq!(popup.best_side(&region(15, 1, 10, 2), &container), "below");
+        assert_eq!(popup.best_side(&region(15, 17, 10, 2), &container), "above");
+        assert_eq!(popup.best_side(&region(0, 8, 4, 4), &container), "right");
+        assert_eq!(popup.best_side(&region(36, 8, 4, 4), &container), "left");
+        // Ties favour the earlier side
+        assert_eq!(popup.best_side(&region(15, 8, 10, 4), &container), "left");
+        assert_eq!(
+            region(0, 0, 4, 4).best_side(&region(8, 8, 4, 4), &region(0, 0, 20, 20)),
+            "above"
+        );
+    }
+
+    #[test]
+    fn test_iter_line_to() {
+        with_py(|py| {
+            let line = |start: GeometryOffset, end: GeometryOffset| {
+                let points = Bound::new(py, start.iter_line_to(end)).unwrap();
+                offsets(points.as_any())
+                    .into_iter()
+                    .map(|point| (point.x, point.y))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                line(offset(0, 0), offset(3, 0)),
+                [(0, 0), (1, 0), (2, 0), (3, 0)]
+            );
+            assert_eq!(line(offset(2, 3), offset(2, 1)), [(2, 3), (2, 2), (2, 1)]);
+            assert_eq!(
+                line(offset(0, 0), offset(3, 3)),
+                [(0, 0), (1, 1), (2, 2), (3, 3)]
+            );
+            assert_eq!(
+                line(offset(0, 0), offset(5, 2)),
+                [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)]
+            );
+            assert_eq!(line(offset(4, 4), offset(4, 4)), [(4, 4)]);
+        });
+    }
+
+    #[test]
+    fn test_border_regions() {
+        for (area, width) in [
+            (region(2, 3, 10, 8), 1),
+            (region(2, 3, 10, 8), 3),
+            (region(0, 0, 3, 3), 2),
+            (region(0, 0, 1, 5), 1),
+        ] {
+            let (top, right, bottom, left) = area.border_regions(width);
+            let strips = [top, right, bottom, left];
+            for (index, strip) in strips.iter().enumerate() {
+                assert!(area.contains_region(strip, false), "{strip:?}");
+                for other in &strips[index + 1..] {
+                    assert!(!strip.overlaps(other), "{strip:?} {other:?}");
+                }
+            }
+            let inner = area.inner(width);
+            let covered: i64 = strips.iter().map(|strip| strip.cell_count()).sum();
+            assert_eq!(covered + inner.cell_count(), area.cell_count());
+        }
+        let (top, right, bottom, left) = region(0, 0, 10, 8).border_regions(1);
+        assert_eq!(top, region(0, 0, 10, 1));
+        assert_eq!(right, region(9, 1, 1, 6));
+        assert_eq!(bottom, region(0, 7, 10, 1));
+        assert_eq!(left, region(0, 1, 1, 6));
+    }
+
+    #[test]
+    fn test_inner() {
+        assert_eq!(region(0, 0, 5, 5).inner(1), region(1, 1, 3, 3));
+        assert_eq!(region(2, 2, 10, 6).inner(2), region(4, 4, 6, 2));
+        assert_eq!(region(0, 0, 5, 5).inner(0), region(0, 0, 5, 5));
+        // Negative borders never grow the region
+        assert_eq!(region(0, 0, 5, 5).inner(-1), region(0, 0, 5, 5));
+        // Regions too small for an interior collapse to nothing
+        let inner = region(0, 0, 2, 1).inner(1);
+        assert_eq!((inner.width, inner.height), (0, 0));
+    }
+
+    #[test]
+    fn test_offset_mod() {
+        with_py(|py| {
+            let wrap = |point: GeometryOffset, bounds: Bound<'_, PyAny>| point.__mod__(&bounds);
+            assert_eq!(
+                wrap(offset(7, 3), to_py(py, size(5, 5))).unwrap(),
+                offset(2, 3)
+            );
+            assert_eq!(
+                wrap(offset(-1, -6), to_py(py, (5, 4))).unwrap(),
+                offset(4, 2)
+            );
+            assert_eq!(
+                wrap(offset(10, 8), to_py(py, (5, 4))).unwrap(),
+                offset(0, 0)
+            );
+            let err = wrap(offset(1, 1), to_py(py, size(0, 5))).unwrap_err();
+            assert!(err.is_instance_of::<PyZeroDivisionError>(py));
+            let err = wrap(offset(1, 1), to_py(py, (5, 0))).unwrap_err();
+            assert!(err.is_instance_of::<PyZeroDivisionError>(py));
+        });
+    }
+
+    #[test]
+    fn test_quantize() {
+        with_py(|py| {
+            let area = region(1, 1, 3, 3);
+            assert_eq!(
+                area.quantize(0.5, 0.5, "expand").unwrap(),
+                region(0, 0, 2, 2)
+            );
+            assert_eq!(
+                area.quantize(0.5, 0.5, "contract").unwrap(),
+                region(1, 1, 1, 1)
+            );
+            assert_eq!(
+                area.quantize(0.5, 0.5, "nearest").unwrap(),
+                region(0, 0, 2, 2)
+            );
+            assert_eq!(
+                region(2, 4, 4, 2).quantize(0.5, 0.5, "contract").unwrap(),
+                region(1, 2, 2, 1)
+            );
+            // Contracting a region thinner than a cell leaves nothing
+            let thin = region(1, 1, 1, 1).quantize(0.5, 0.5, "contract").unwrap();
+            assert_eq!((thin.width, thin.height), (0, 0));
+            let err = area.quantize(0.5, 0.5, "floor").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_region_at_cell() {
+        let cell = size(4, 2);
+        assert_eq!(cell.region_at_cell(0, 0), region(0, 0, 4, 2));
+        assert_eq!(cell.region_at_cell(3, 1), region(12, 2, 4, 2));
+        assert_eq!(cell.region_at_cell(-1, 2), region(-4, 4, 4, 2));
+    }
+
+    #[test]
+    fn test_region_dict_round_trip() {
+        with_py(|py| {
+            let cls = py.get_type::<Region>();
+            let original = region(1, -2, 3, 4);
+            let values = original.as_dict(py).unwrap();
+            assert_eq!(Region::from_dict(&cls, &values).unwrap(), original);
+
+            let missing = PyDict::new(py);
+            missing.set_item("x", 1).unwrap();
+            missing.set_item("y", 2).unwrap();
+            missing.set_item("width", 3).unwrap();
+            let err = Region::from_dict(&cls, &missing).unwrap_err();
+            assert!(err.is_instance_of::<PyKeyError>(py));
+
+            values.set_item("depth", 5).unwrap();
+            let err = Region::from_dict(&cls, &values).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_clamp_magnitude() {
+        with_py(|py| {
+            assert_eq!(offset(3, 4).clamp_magnitude(10.0).unwrap(), offset(3, 4));
+            assert_eq!(offset(3, 4).clamp_magnitude(5.0).unwrap(), offset(3, 4));
+            assert_eq!(offset(6, -8).clamp_magnitude(5.0).unwrap(), offset(3, -4));
+            // Components round towards zero, so the limit is never exceeded
+            let clamped = offset(7, 7).clamp_magnitude(5.0).unwrap();
+            assert_eq!(clamped, offset(3, 3));
+            assert!(clamped.magnitude() <= 5.0);
+            assert_eq!(offset(3, 4).clamp_magnitude(0.0).unwrap(), offset(0, 0));
+            let err = offset(3, 4).clamp_magnitude(-1.0).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_with_center() {
+        with_py(|py| {
+            let center = to_py(py, (10, 10));
+            assert_eq!(
+                region(0, 0, 4, 6).with_center(&center).unwrap(),
+                region(8, 7, 4, 6)
+            );
+            // Odd sizes put the extra cell after the center
+            assert_eq!(
+                region(0, 0, 3, 5).with_center(&center).unwrap(),
+                region(8, 7, 3, 5)
+            );
+            assert_eq!(
+                region(0, 0, 3, 3)
+                    .with_center(&to_py(py, offset(-1, -1)))
+                    .unwrap(),
+                region(-3, -3, 3, 3)
+            );
+        });
+    }
+
+    #[test]
+    fn test_scale_clamped() {
+        let padding = spacing(1, 2, 3, 4);
+        assert_eq!(padding.scale_clamped(2.0, 10), spacing(2, 4, 6, 8));
+        assert_eq!(padding.scale_clamped(2.0, 5), spacing(2, 4, 5, 5));
+        assert_eq!(padding.scale_clamped(0.5, 10), spacing(0, 1, 1, 2));
+    }
+
+    #[test]
+    fn test_split_at_columns() {
+        with_py(|py| {
+            let area = region(2, 1, 10, 3);
+            assert_eq!(
+                area.split_at_columns(vec![]).unwrap(),
+                [region(2, 1, 10, 3)]
+            );
+            assert_eq!(
+                area.split_at_columns(vec![3, 7]).unwrap(),
+                [region(2, 1, 3, 3), region(5, 1, 4, 3), region(9, 1, 3, 3)]
+            );
+            assert_eq!(
+                area.split_at_columns(vec![0, 10]).unwrap(),
+                [region(2, 1, 0, 3), region(2, 1, 10, 3), region(12, 1, 0, 3)]
+            );
+            for cuts in [vec![7, 3], vec![-1], vec![11]] {
+                let err = area.split_at_columns(cuts).unwrap_err();
+                assert!(err.is_instance_of::<PyValueError>(py));
+            }
+        });
+    }
+
+    #[test]
+    fn test_same_size() {
+        with_py(|py| {
+            let area = region(5, 5, 3, 2);
+            assert!(area.same_size(&to_py(py, region(0, 0, 3, 2))).unwrap());
+            assert!(!area.same_size(&to_py(py, region(5, 5, 2, 3))).unwrap());
+            assert!(area.same_size(&to_py(py, size(3, 2))).unwrap());
+            assert!(area.same_size(&to_py(py, (3, 2))).unwrap());
+            assert!(!area.same_size(&to_py(py, (3, 3))).unwrap());
+            assert!(area.same_size(&to_py(py, "3x2")).is_err());
+        });
+    }
+
+    #[test]
+    fn test_offset_clamp_inclusive() {
+        assert_eq!(offset(12, 12).clamp(10, 5, false), offset(9, 4));
+        assert_eq!(offset(12, 12).clamp(10, 5, true), offset(10, 5));
+        assert_eq!(offset(-3, 2).clamp(10, 5, true), offset(0, 2));
+        assert_eq!(offset(3, 3).clamp(0, 0, false), offset(0, 0));
+        assert_eq!(offset(3, 3).clamp(0, 0, true), offset(0, 0));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(region(1, 2, 3, 4).normalize(), region(1, 2, 3, 4));
+        assert_eq!(region(5, 5, -3, 2).normalize(), region(2, 5, 3, 2));
+        assert_eq!(region(5, 5, 3, -2).normalize(), region(5, 3, 3, 2));
+        assert_eq!(region(5, 5, -3, -2).normalize(), region(2, 3, 3, 2));
+        let normalized = region(5, 5, -3, -2).normalize();
+        assert_eq!(normalized.normalize(), normalized);
+    }
+
+    #[test]
+    fn test_for_area() {
+        with_py(|py| {
+            let cls = py.get_type::<Size>();
+            assert_eq!(Size::for_area(&cls, 0, 1.0).unwrap(), size(0, 0));
+            assert_eq!(Size::for_area(&cls, 16, 1.0).unwrap(), size(4, 4));
+            assert_eq!(Size::for_area(&cls, 10, 1.0).unwrap(), size(4, 3));
+            assert_eq!(Size::for_area(&cls, 32, 2.0).unwrap(), size(8, 4));
+            for (area, aspect) in [(1, 1.0), (7, 3.0), (100, 0.25), (1000, 0.001)] {
+                let Size { width, height } = Size::for_area(&cls, area, aspect).unwrap();
+                assert!(width >= 1 && height >= 1);
+                assert!(width as i64 * height as i64 >= area as i64);
+            }
+            let err = Size::for_area(&cls, -1, 1.0).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            let err = Size::for_area(&cls, 10, 0.0).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_iter_visible_lines() {
+        with_py(|py| {
+            let lines = |area: Region, viewport: Region| -> Vec<i32> {
+                area.iter_visible_lines(py, &viewport)
+                    .unwrap()
+                    .map(|line| line.unwrap().extract().unwrap())
+                    .collect()
+            };
+            let viewport = region(0, 10, 20, 5);
+            assert_eq!(lines(region(2, 8, 4, 4), viewport), [10, 11]);
+            assert_eq!(lines(region(2, 12, 4, 10), viewport), [12, 13, 14]);
+            assert_eq!(lines(region(2, 11, 4, 2), viewport), [11, 12]);
+            assert!(lines(region(2, 0, 4, 10), viewport).is_empty());
+            assert!(lines(region(30, 10, 4, 4), viewport).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_is_between() {
+        with_py(|py| {
+            let between = |point: GeometryOffset, a: (i32, i32), b: (i32, i32)| {
+                point.is_between(&to_py(py, a), &to_py(py, b)).unwrap()
+            };
+            assert!(between(offset(2, 2), (0, 0), (4, 4)));
+            assert!(between(offset(0, 0), (0, 0), (4, 4)));
+            assert!(between(offset(4, 4), (4, 4), (0, 0)));
+            assert!(!between(offset(5, 5), (0, 0), (4, 4)));
+            assert!(!between(offset(2, 3), (0, 0), (4, 4)));
+            assert!(between(offset(3, 7), (3, 1), (3, 9)));
+            assert!(between(offset(2, 2), (2, 2), (2, 2)));
+            // The cross product of extreme coordinates doesn't fit in an i64
+            let (min, max) = (i32::MIN, i32::MAX);
+            assert!(!between(offset(max, max), (min, min), (max, min)));
+            assert!(between(offset(max, min), (min, min), (max, min)));
+            assert!(between(offset(0, 0), (min + 1, min + 1), (max, max)));
+        });
+    }
+
+    #[test]
+    fn test_weighted_spans() {
+        assert_eq!(weighted_spans(0, 10, &[1, 1]).unwrap(), [(0, 5), (5, 5)]);
+        assert_eq!(
+            weighted_spans(0, 10, &[1, 1, 1]).unwrap(),
+            [(0, 4), (4, 3), (7, 3)]
+        );
+        // Zero weights get nothing, even when there are leftover cells
+        assert_eq!(
+            weighted_spans(5, 10, &[1, 0, 2]).unwrap(),
+            [(5, 4), (9, 0), (9, 6)]
+        );
+        assert_eq!(weighted_spans(0, -3, &[1, 2]).unwrap(), [(0, 0), (0, 0)]);
+        with_py(|py| {
+            for weights in [vec![], vec![0, 0], vec![1, -1]] {
+                let err = weighted_spans(0, 10, &weights).unwrap_err();
+                assert!(err.is_instance_of::<PyValueError>(py));
+            }
+        });
+    }
+
+    #[test]
+    fn test_fit_columns() {
+        let area = region(2, 3, 10, 4);
+        assert_eq!(
+            area.fit_columns(vec![1, 1, 1]).unwrap(),
+            [region(2, 3, 4, 4), region(6, 3, 3, 4), region(9, 3, 3, 4)]
+        );
+        assert_eq!(
+            area.fit_columns(vec![3, 2]).unwrap(),
+            [region(2, 3, 6, 4), region(8, 3, 4, 4)]
+        );
+        assert!(area.fit_columns(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_fit_rows() {
+        let area = region(2, 3, 4, 10);
+        assert_eq!(
+            area.fit_rows(vec![1, 1, 1]).unwrap(),
+            [region(2, 3, 4, 4), region(2, 7, 4, 3), region(2, 10, 4, 3)]
+        );
+        assert_eq!(
+            area.fit_rows(vec![0, 1]).unwrap(),
+            [region(2, 3, 4, 0), region(2, 3, 4, 10)]
+        );
+        assert!(area.fit_rows(vec![0]).is_err());
+    }
+
+    #[test]
+    fn test_offset_to_region() {
+        with_py(|py| {
+            let point = offset(3, -2);
+            assert_eq!(point.to_region(None).unwrap(), region(3, -2, 1, 1));
+            assert_eq!(
+                point.to_region(Some(&to_py(py, (4, 5)))).unwrap(),
+                region(3, -2, 4, 5)
+            );
+            assert_eq!(
+                point.to_region(Some(&to_py(py, size(2, 0)))).unwrap(),
+                region(3, -2, 2, 0)
+            );
+            // Negative sizes are clamped rather than producing an inverted region
+            assert_eq!(
+                point.to_region(Some(&to_py(py, (-4, 5)))).unwrap(),
+                region(3, -2, 0, 5)
+            );
+        });
+    }
+
+    #[test]
+    fn test_expand_to_include() {
+        with_py(|py| {
+            let area = region(2, 2, 3, 3);
+            let expand = |other: Bound<'_, PyAny>| area.expand_to_include(&other).unwrap();
+            assert_eq!(expand(to_py(py, (3, 3))), area);
+            assert_eq!(expand(to_py(py, (8, 0))), region(2, 0, 7, 5));
+            assert_eq!(expand(to_py(py, offset(0, 6))), region(0, 2, 5, 5));
+            assert_eq!(expand(to_py(py, region(6, 6, 2, 2))), region(2, 2, 6, 6));
+            assert!(area.expand_to_include(&to_py(py, "corner")).is_err());
+        });
+    }
+
+    #[test]
+    fn test_spacing_scalar_arithmetic() {
+        with_py(|py| {
+            let padding = spacing(1, 2, 3, 4);
+            assert_eq!(padding.__add__(&to_py(py, 2)).unwrap(), spacing(3, 4, 5, 6));
+            assert_eq!(padding.__sub__(&to_py(py, 1)).unwrap(), spacing(0, 1, 2, 3));
+            assert_eq!(
+                padding.__radd__(&to_py(py, 2)).unwrap(),
+                spacing(3, 4, 5, 6)
+            );
+            let locals = PyDict::new(py);
+            locals
+                .set_item("Spacing", py.get_type::<Spacing>())
+                .unwrap();
+            let result: Spacing = py
+                .eval(c"2 + Spacing(1, 2, 3, 4)", None, Some(&locals))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(result, spacing(3, 4, 5, 6));
+            let err = padding.__add__(&to_py(py, 1.5)).unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_with_offset() {
+        with_py(|py| {
+            let area = region(5, 5, 2, 3);
+            let shift = to_py(py, offset(1, -2));
+            assert_eq!(area.with_offset(&shift).unwrap(), region(1, -2, 2, 3));
+            assert_eq!(area.translate(&shift).unwrap(), region(6, 3, 2, 3));
+            assert_eq!(
+                area.with_offset(&to_py(py, (0, 0))).unwrap(),
+                region(0, 0, 2, 3)
+            );
+        });
+    }
+
+    #[test]
+    fn test_region_between() {
+        with_py(|py| {
+            let cls = py.get_type::<GeometryOffset>();
+            let between = |a: (i32, i32), b: (i32, i32)| {
+                GeometryOffset::region_between(&cls, &to_py(py, a), &to_py(py, b)).unwrap()
+            };
+            assert_eq!(between((1, 2), (4, 6)), region(1, 2, 4, 5));
+            // Corners can be given in any order
+            assert_eq!(between((4, 6), (1, 2)), region(1, 2, 4, 5));
+            assert_eq!(between((4, 2), (1, 6)), region(1, 2, 4, 5));
+            assert_eq!(between((3, 3), (3, 3)), region(3, 3, 1, 1));
+        });
+    }
+
+    #[test]
+    fn test_clip_checked() {
+        with_py(|py| {
+            assert_eq!(
+                region(1, 1, 3, 3).clip_checked(10, 10).unwrap(),
+                (region(1, 1, 3, 3), false)
+            );
+            assert_eq!(
+                region(8, -2, 5, 5).clip_checked(10, 10).unwrap(),
+                (region(8, 0, 2, 3), true)
+            );
+            let err = region(1, 1, 3, 3).clip_checked(-1, 10).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_contains_x_and_y() {
+        let area = region(2, 3, 4, 5);
+        assert!(area.contains_x(2));
+        assert!(area.contains_x(5));
+        assert!(!area.contains_x(1));
+        assert!(!area.contains_x(6));
+        assert!(area.contains_y(3));
+        assert!(area.contains_y(7));
+        assert!(!area.contains_y(2));
+        assert!(!area.contains_y(8));
+        // Each axis is checked independently
+        assert!(area.contains_x(3) && !area.contains(3, 100));
+        assert!(!region(0, 0, 0, 5).contains_x(0));
+    }
+
+    #[test]
+    fn test_reflect() {
+        let area = region(0, 0, 10, 6);
+        // Corners map to the opposite corner
+        assert_eq!(offset(0, 0).reflect(&area), offset(9, 5));
+        assert_eq!(offset(9, 5).reflect(&area), offset(0, 0));
+        assert_eq!(offset(9, 0).reflect(&area), offset(0, 5));
+        assert_eq!(offset(2, 1).reflect(&area), offset(7, 4));
+        assert_eq!(offset(2, 1).reflect(&area).reflect(&area), offset(2, 1));
+        assert_eq!(
+            offset(2, 1).reflect(&area),
+            offset(2, 1).mirror_x(10).mirror_y(6)
+        );
+        // The center cell of an odd-sized region is fixed
+        let odd = region(0, 0, 5, 5);
+        assert_eq!(offset(2, 2).reflect(&odd), offset(2, 2));
+        assert_eq!(offset(1, 1).reflect(&odd), offset(3, 3));
+        let moved = region(2, 3, 4, 4);
+        assert_eq!(offset(2, 3).reflect(&moved), offset(5, 6));
+        assert_eq!(offset(-3, 0).reflect(&moved), offset(10, 9));
+    }
+
+    #[test]
+    fn test_size_eq() {
+        with_py(|py| {
+            let locals = PyDict::new(py);
+            locals.set_item("Size", py.get_type::<Size>()).unwrap();
+            locals.set_item("Region", py.get_type::<Region>()).unwrap();
+            let check = |expression: &std::ffi::CStr| -> bool {
+                py.eval(expression, None, Some(&locals))
+                    .unwrap()
+                    .extract()
+                    .unwrap()
+            };
+            assert!(!check(c"Size(3, 4) == Region(1, 2, 3, 4)"));
+            assert!(!check(c"Region(1, 2, 3, 4) == Size(3, 4)"));
+            assert!(check(c"Size(3, 4) != Region(1, 2, 3, 4)"));
+            assert!(check(c"Size(3, 4) == Region(1, 2, 3, 4).size"));
+            assert!(!check(c"Size(3, 4) == Region(1, 2, 4, 3).size"));
+            // Equality stays consistent with hashing for set and dict keys
+            assert!(!check(c"Region(0, 0, 3, 4) in {Size(3, 4)}"));
+            assert!(check(c"Region(0, 0, 3, 4).size in {Size(3, 4)}"));
+            assert!(check(c"len({Size(3, 4): 1, Region(0, 0, 3, 4): 2}) == 2"));
+            assert!(check(c"Size(3, 4) == (3, 4)"));
+            assert!(check(c"Size(3, 4) != (4, 3)"));
+            // Unrelated types fall back to Python's default comparison
+            assert!(!check(c"Size(3, 4) == 'Size(3, 4)'"));
+            assert!(check(c"Size(3, 4).__eq__(None) is NotImplemented"));
+        });
+    }
+
+    #[test]
+    fn test_scrollbar_thumb() {
+        with_py(|py| {
+            let cls = py.get_type::<Region>();
+            let thumb = |window: i32, content: i32, position: i32| {
+                Region::scrollbar_thumb(&cls, window, content, position)
+            };
+            assert_eq!(thumb(10, 5, 0), (0, 10));
+            assert_eq!(thumb(10, 10, 3), (0, 10));
+            assert_eq!(thumb(10, 20, 0), (0, 5));
+            assert_eq!(thumb(10, 20, 10), (5, 5));
+            assert_eq!(thumb(10, 20, 5), (2, 5));
+            // Out of range positions are clamped
+            assert_eq!(thumb(10, 20, -5), (0, 5));
+            assert_eq!(thumb(10, 20, 50), (5, 5));
+            // The thumb is always at least one cell
+            assert_eq!(thumb(10, 1000, 990), (9, 1));
+            assert_eq!(thumb(0, 100, 10), (0, 0));
+        });
+    }
+
+    #[test]
+    fn test_region_sub_dispatch() {
+        with_py(|py| {
+            let r = region(1, 2, 10, 10);
+            assert_eq!(
+                r.__sub__(&to_py(py, size(2, 3))).unwrap(),
+                region(1, 2, 8, 7)
+            );
+            assert_eq!(
+                r.__sub__(&to_py(py, offset(2, 3))).unwrap(),
+                region(-1, -1, 10, 10)
+            );
+            assert_eq!(
+                r.__sub__(&to_py(py, (2, 3))).unwrap(),
+                region(-1, -1, 10, 10)
+            );
+            // Shrinking stops at an empty region
+            assert_eq!(
+                r.__sub__(&to_py(py, size(20, 1))).unwrap(),
+                region(1, 2, 0, 9)
+            );
+            let grow = to_py(py, size(4, 5));
+            assert_eq!(r.__add__(&grow).unwrap().__sub__(&grow).unwrap(), r);
+            let error = r.__sub__(&to_py(py, "nope")).unwrap_err();
+            assert!(error.is_instance_of::<PyTypeError>(py));
+        });
+    }
 }
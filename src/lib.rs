@@ -15,5 +15,9 @@ fn textual_speedups(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<geometry::Size>()?;
     m.add_class::<geometry::Region>()?;
     m.add_class::<geometry::Spacing>()?;
+    m.add_function(wrap_pyfunction!(geometry::coalesce_regions, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::distances, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::distances_squared, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::summarize_regions, m)?)?;
     Ok(())
 }